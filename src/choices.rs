@@ -0,0 +1,66 @@
+// Messages offering colors to pick with numbered reactions. Like pagers, they
+// live in the client's data and are forgotten on restart.
+
+use std::collections::HashMap;
+
+use serenity::CACHE;
+use serenity::client::Context;
+use serenity::model::channel::{Message, Reaction, ReactionType};
+use serenity::model::id::MessageId;
+use typemap::Key;
+
+use color::Color;
+
+/// Keycaps 1 through 9; 🔟 is a different kind of emoji.
+pub const MAX: usize = 9;
+/// How many offers to remember before forgetting the oldest.
+const LIMIT: usize = 100;
+
+pub struct Choices;
+impl Key for Choices {
+    type Value = HashMap<MessageId, Vec<Color>>;
+}
+
+/// The keycap emoji for choice `i`, counting from 0.
+pub fn emoji(i: usize) -> String {
+    format!("{}\u{fe0f}\u{20e3}", i + 1)
+}
+
+/// Adds a numbered reaction to `msg` for each of `colors`, up to `MAX`, and
+/// remembers them.
+pub fn offer(ctx: &Context, msg: &Message, mut colors: Vec<Color>) {
+    colors.truncate(MAX);
+    let count = colors.len();
+    {
+        let mut data = ctx.data.lock();
+        let choices = data.entry::<Choices>().or_insert_with(HashMap::new);
+        if choices.len() >= LIMIT {
+            if let Some(&oldest) = choices.keys().min() {
+                choices.remove(&oldest);
+            }
+        }
+        choices.insert(msg.id, colors);
+    }
+    for i in 0..count {
+        let _ = msg.react(emoji(i));
+    }
+}
+
+/// The color `reaction` picks, if it's a choice on an offer.
+pub fn chosen(ctx: &Context, reaction: &Reaction) -> Option<Color> {
+    if reaction.user_id == CACHE.read().user.id {
+        return None;
+    }
+    // Discord sometimes drops the variation selector from keycaps.
+    let i = match reaction.emoji {
+        ReactionType::Unicode(ref s) if s.ends_with('\u{20e3}') => {
+            s.chars().next()?.to_digit(10)? as usize
+        }
+        _ => return None,
+    };
+    let data = ctx.data.lock();
+    data.get::<Choices>()?
+        .get(&reaction.message_id)?
+        .get(i.checked_sub(1)?)
+        .cloned()
+}
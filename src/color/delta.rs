@@ -0,0 +1,173 @@
+// Perceptual color differences. A ΔE of about 1 (or 0.02 in OKLab) is the
+// smallest difference most people can see.
+
+use super::{Lab, Oklab};
+
+/// Euclidean distance in OKLab, as used by CSS Color 4 gamut mapping.
+pub fn delta_eok(x: Oklab, y: Oklab) -> f64 {
+    ((x.l - y.l).powi(2) + (x.a - y.a).powi(2) + (x.b - y.b).powi(2)).sqrt()
+}
+
+/// CIEDE2000, with the weighting factors kL, kC and kH all 1.
+pub fn delta_e2000(x: Lab, y: Lab) -> f64 {
+    let pow25_7 = 25f64.powi(7);
+
+    let c1 = x.a.hypot(x.b);
+    let c2 = y.a.hypot(y.b);
+    let c_bar7 = ((c1 + c2) / 2.0).powi(7);
+    let g = 0.5 * (1.0 - (c_bar7 / (c_bar7 + pow25_7)).sqrt());
+
+    let a1 = x.a * (1.0 + g);
+    let a2 = y.a * (1.0 + g);
+    let c1 = a1.hypot(x.b);
+    let c2 = a2.hypot(y.b);
+    let hue = |a: f64, b: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            let h = b.atan2(a).to_degrees();
+            if h < 0.0 {
+                h + 360.0
+            } else {
+                h
+            }
+        }
+    };
+    let h1 = hue(a1, x.b);
+    let h2 = hue(a2, y.b);
+
+    let dl = y.l - x.l;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh_big = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (x.l + y.l) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let c_bar7 = c_bar.powi(7);
+    let r_c = 2.0 * (c_bar7 / (c_bar7 + pow25_7)).sqrt();
+    let l_50 = (l_bar - 50.0).powi(2);
+    let s_l = 1.0 + 0.015 * l_50 / (20.0 + l_50).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+    let l_term = dl / s_l;
+    let c_term = dc / s_c;
+    let h_term = dh_big / s_h;
+    (l_term.powi(2) + c_term.powi(2) + h_term.powi(2) + r_t * c_term * h_term).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lab(l: f64, a: f64, b: f64) -> Lab {
+        Lab { l, a, b }
+    }
+
+    // Two CIELAB colors and the ΔE between them.
+    type Pair = ((f64, f64, f64), (f64, f64, f64), f64);
+
+    // From Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula:
+    // Implementation Notes, Supplementary Test Data, and Mathematical
+    // Observations" (2005), table 1.
+    const SHARMA: [Pair; 34] = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+        ((50.0, 2.8361, -74.0200), (50.0, 0.0, -82.7485), 3.4412),
+        ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000),
+        ((50.0, -1.1848, -84.8006), (50.0, 0.0, -82.7485), 1.0000),
+        ((50.0, -0.9009, -85.5211), (50.0, 0.0, -82.7485), 1.0000),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+        ((50.0, -1.0, 2.0), (50.0, 0.0, 0.0), 2.3669),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0009), 7.1792),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0010), 7.1792),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0011), 7.2195),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0012), 7.2195),
+        ((50.0, -0.0010, 2.4900), (50.0, 0.0009, -2.4900), 4.8045),
+        ((50.0, -0.0010, 2.4900), (50.0, 0.0010, -2.4900), 4.8045),
+        ((50.0, -0.0010, 2.4900), (50.0, 0.0011, -2.4900), 4.7461),
+        ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        ((50.0, 2.5, 0.0), (61.0, -5.0, 29.0), 22.8977),
+        ((50.0, 2.5, 0.0), (56.0, -27.0, -3.0), 31.9030),
+        ((50.0, 2.5, 0.0), (58.0, 24.0, 15.0), 19.4535),
+        ((50.0, 2.5, 0.0), (50.0, 3.1736, 0.5854), 1.0000),
+        ((50.0, 2.5, 0.0), (50.0, 3.2972, 0.0), 1.0000),
+        ((50.0, 2.5, 0.0), (50.0, 1.8634, 0.5757), 1.0000),
+        ((50.0, 2.5, 0.0), (50.0, 3.2592, 0.3350), 1.0000),
+        ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+        ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630),
+        ((61.2901, 3.7196, -5.3901), (61.4292, 2.2480, -4.9620), 1.8731),
+        ((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645),
+        ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+        ((36.4612, 47.8580, 18.3852), (36.2715, 50.5065, 21.2231), 1.4146),
+        ((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), 1.4441),
+        ((90.9257, -0.5406, -0.9208), (88.6381, -0.8985, -0.7239), 1.5381),
+        ((6.7747, -0.2908, -2.4247), (5.8714, -0.0985, -2.2286), 0.6377),
+        ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082),
+    ];
+
+    #[test]
+    fn ciede2000_matches_sharma() {
+        for &((l1, a1, b1), (l2, a2, b2), expected) in SHARMA.iter() {
+            let (x, y) = (lab(l1, a1, b1), lab(l2, a2, b2));
+            let d = delta_e2000(x, y);
+            assert!((d - expected).abs() < 1e-4, "{:?} {:?}: {} != {}", x, y, d, expected);
+            // It's symmetric.
+            assert!((delta_e2000(y, x) - d).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn identical_colors_are_zero_apart() {
+        let x = lab(53.2, 80.1, 67.2);
+        assert_eq!(delta_e2000(x, x), 0.0);
+        let ok = Oklab {
+            l: 0.5,
+            a: 0.1,
+            b: -0.1,
+        };
+        assert_eq!(delta_eok(ok, ok), 0.0);
+    }
+
+    #[test]
+    fn oklab_distance_is_euclidean() {
+        let (x, y) = (
+            Oklab {
+                l: 0.5,
+                a: 0.0,
+                b: 0.0,
+            },
+            Oklab {
+                l: 0.5,
+                a: 0.03,
+                b: 0.04,
+            },
+        );
+        assert!((delta_eok(x, y) - 0.05).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,224 @@
+// Representative colors for emoji, so `%color set 🍊` gets the orange members
+// see. Each emoji maps to the flat color of its main fill in Twemoji (the set
+// Discord draws), picked by hand from Twemoji's palette; skin tones are its
+// base skin colors. These aren't averages of the artwork, which would lean
+// toward outlines and highlights, so shaded designs can look a little darker
+// or lighter than their entry. Keys are stored without the U+FE0F variation
+// selector, which `lookup` strips.
+
+use super::Color;
+
+static EMOJI: &[(&str, u32)] = &[
+    // Hearts
+    ("❤", 0xdd2e44),
+    ("🧡", 0xf4900c),
+    ("💛", 0xfdcb58),
+    ("💚", 0x78b159),
+    ("💙", 0x5dadec),
+    ("💜", 0xaa8ed6),
+    ("🤎", 0xc1694f),
+    ("🖤", 0x31373d),
+    ("🤍", 0xe1e8ed),
+    ("🩷", 0xf4abba),
+    ("🩵", 0x88c9f9),
+    ("🩶", 0x99aab5),
+    // Shapes
+    ("🔴", 0xdd2e44),
+    ("🟠", 0xf4900c),
+    ("🟡", 0xfdcb58),
+    ("🟢", 0x78b159),
+    ("🔵", 0x55acee),
+    ("🟣", 0xaa8ed6),
+    ("🟤", 0xc1694f),
+    ("⚫", 0x31373d),
+    ("⚪", 0xe6e7e8),
+    ("🟥", 0xdd2e44),
+    ("🟧", 0xf4900c),
+    ("🟨", 0xfdcb58),
+    ("🟩", 0x78b159),
+    ("🟦", 0x55acee),
+    ("🟪", 0xaa8ed6),
+    ("🟫", 0xc1694f),
+    ("⬛", 0x292f33),
+    ("⬜", 0xe6e7e8),
+    // Food
+    ("🍎", 0xdd2e44),
+    ("🍏", 0x77b255),
+    ("🍐", 0xa6d388),
+    ("🍊", 0xf4900c),
+    ("🍋", 0xffcc4d),
+    ("🍌", 0xffd983),
+    ("🍉", 0xea596e),
+    ("🍇", 0x744eaa),
+    ("🍓", 0xbe1931),
+    ("🫐", 0x5864b7),
+    ("🍒", 0xbe1931),
+    ("🍑", 0xff886c),
+    ("🍍", 0xffac33),
+    ("🥝", 0x77b255),
+    ("🍅", 0xdd2e44),
+    ("🍆", 0x744eaa),
+    ("🥑", 0x77b255),
+    ("🥦", 0x5c913b),
+    ("🥒", 0x77b255),
+    ("🌶", 0xbe1931),
+    ("🌽", 0xffcc4d),
+    ("🥕", 0xf4900c),
+    ("🧀", 0xffcc4d),
+    ("🍫", 0x8a4b38),
+    ("☕", 0x8a4b38),
+    ("🍵", 0x77b255),
+    ("🍷", 0xbe1931),
+    // Nature
+    ("🌹", 0xbe1931),
+    ("🌷", 0xea596e),
+    ("🌸", 0xf4abba),
+    ("🌺", 0xea596e),
+    ("🌻", 0xffac33),
+    ("🌼", 0xffd983),
+    ("🍀", 0x5c913b),
+    ("🌿", 0x77b255),
+    ("🌱", 0x77b255),
+    ("🌲", 0x3e721d),
+    ("🌳", 0x5c913b),
+    ("🌵", 0x77b255),
+    ("🍁", 0xdd2e44),
+    ("🍂", 0xc1694f),
+    ("🌊", 0x3b88c3),
+    ("🔥", 0xf4900c),
+    ("⭐", 0xffac33),
+    ("🌟", 0xffac33),
+    ("☀", 0xffac33),
+    ("🌙", 0xffd983),
+    ("☁", 0xe1e8ed),
+    ("❄", 0x88c9f9),
+    ("💧", 0x5dadec),
+    ("💎", 0x88c9f9),
+    // Animals
+    ("🐸", 0x77b255),
+    ("🐷", 0xf4abba),
+    ("🦊", 0xf4900c),
+    ("🐻", 0xc1694f),
+    ("🐳", 0x3b88c3),
+    ("🐬", 0x55acee),
+    ("🦩", 0xea596e),
+    ("🐝", 0xffcc4d),
+    ("🐞", 0xdd2e44),
+    ("🦋", 0x55acee),
+    ("🐤", 0xffcc4d),
+    ("🐉", 0x77b255),
+    ("🐲", 0x77b255),
+    // Faces and things
+    ("😀", 0xffcc4d),
+    ("😡", 0xda2f47),
+    ("🥶", 0x5dadec),
+    ("🤢", 0x77b255),
+    ("😈", 0xaa8ed6),
+    ("👿", 0xaa8ed6),
+    ("👾", 0xaa8ed6),
+    ("🎃", 0xf4900c),
+    ("💩", 0x8a4b38),
+];
+
+// Skin tone modifiers, which color the hand or person emoji they follow.
+static SKIN_TONES: &[(char, u32)] = &[
+    ('\u{1f3fb}', 0xf7dece),
+    ('\u{1f3fc}', 0xf3d2a2),
+    ('\u{1f3fd}', 0xd4ab88),
+    ('\u{1f3fe}', 0xaf7e57),
+    ('\u{1f3ff}', 0x7c533e),
+];
+
+// Hands and people that take a skin tone, without U+FE0F.
+static MODIFIER_BASES: &[&str] = &[
+    // Hands
+    "👋", "🤚", "🖐", "✋", "🖖", "👌", "🤌", "🤏", "✌", "🤞", "🤟", "🤘", "🤙", "👈", "👉",
+    "👆", "🖕", "👇", "☝", "👍", "👎", "✊", "👊", "🤛", "🤜", "👏", "🙌", "👐", "🤲", "🙏",
+    "✍", "💅", "🤳", "💪",
+    // People
+    "👶", "🧒", "👦", "👧", "🧑", "👱", "👨", "🧔", "👩", "🧓", "👴", "👵", "🙍", "🙎", "🙅",
+    "🙆", "💁", "🙋", "🙇", "🤦", "🤷", "👮", "💂", "👷", "🤴", "👸", "👳", "👲", "🧕", "🤵",
+    "👰", "🤰", "👼", "🎅", "🤶", "🦸", "🦹", "🧙", "🧚", "🧛", "🧜", "🧝", "💆", "💇", "🚶",
+    "🏃", "💃", "🕺", "🧘",
+];
+
+fn rgb(rgb: u32) -> Color {
+    Color((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// The color of emoji `s`. A hand or person with a skin tone, like 👋🏽, is
+/// that tone; longer sequences with one in the middle, like 👩🏽‍💻, are not.
+pub fn lookup(s: &str) -> Option<Color> {
+    let s = s.trim().replace('\u{fe0f}', "");
+    if let Some(&(_, color)) = EMOJI.iter().find(|&&(e, _)| e == s) {
+        return Some(rgb(color));
+    }
+    let last = s.chars().last()?;
+    let base = &s[..s.len() - last.len_utf8()];
+    if !MODIFIER_BASES.contains(&base) {
+        return None;
+    }
+    SKIN_TONES
+        .iter()
+        .find(|&&(tone, _)| tone == last)
+        .map(|&(_, color)| rgb(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_entries() {
+        assert_eq!(lookup("🍊"), Some(Color(0xf4, 0x90, 0x0c)));
+        assert_eq!(lookup(" 💙 "), Some(Color(0x5d, 0xad, 0xec)));
+        assert_eq!(lookup("🍅"), Some(Color(0xdd, 0x2e, 0x44)));
+        assert_eq!(lookup("🍇"), Some(Color(0x74, 0x4e, 0xaa)));
+        assert_eq!(lookup("🌲"), Some(Color(0x3e, 0x72, 0x1d)));
+        assert_eq!(lookup("⬛"), Some(Color(0x29, 0x2f, 0x33)));
+    }
+
+    #[test]
+    fn table_is_well_formed() {
+        for &(e, color) in EMOJI {
+            assert!(!e.is_empty() && !e.contains('\u{fe0f}'), "{}", e);
+            assert!(color <= 0xff_ffff, "{}", e);
+            assert_eq!(EMOJI.iter().filter(|&&(other, _)| other == e).count(), 1, "{}", e);
+        }
+    }
+
+    #[test]
+    fn variation_selectors_are_ignored() {
+        assert_eq!(lookup("❤\u{fe0f}"), lookup("❤"));
+        assert!(lookup("❤").is_some());
+    }
+
+    #[test]
+    fn skin_tones_at_the_end() {
+        assert_eq!(lookup("👋🏽"), Some(Color(0xd4, 0xab, 0x88)));
+        assert_eq!(lookup("👍🏿"), Some(Color(0x7c, 0x53, 0x3e)));
+        assert_eq!(lookup("\u{270c}\u{fe0f}\u{1f3fb}"), Some(Color(0xf7, 0xde, 0xce)));
+        assert_eq!(lookup("👩🏾"), Some(Color(0xaf, 0x7e, 0x57)));
+    }
+
+    #[test]
+    fn skin_tones_elsewhere_dont_count() {
+        assert_eq!(lookup("👩🏽\u{200d}💻"), None);
+        assert_eq!(lookup("🏽x"), None);
+    }
+
+    #[test]
+    fn skin_tones_need_a_base() {
+        assert_eq!(lookup("🏽"), None);
+        assert_eq!(lookup("abc🏽"), None);
+        assert_eq!(lookup("🍊🏽"), None);
+        assert_eq!(lookup("👋👋🏽"), None);
+    }
+
+    #[test]
+    fn unknown_emoji() {
+        assert_eq!(lookup("🦄"), None);
+        assert_eq!(lookup("red"), None);
+        assert_eq!(lookup(""), None);
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::str;
+
+use failure::{Compat, Error};
+use serenity::utils::Colour as SColour;
+
+mod delta;
+mod emoji;
+mod parse;
+mod space;
+
+pub use self::delta::{delta_e2000, delta_eok};
+pub use self::space::{Hsl, Hsv, Lab, LinearRgb, Oklab, Oklch, Rgb};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Color(pub u8, pub u8, pub u8);
+impl Color {
+    /// Quantizes sRGB components in `0.0..=1.0`, clamping anything outside.
+    pub fn from_unit(r: f64, g: f64, b: f64) -> Color {
+        fn q(x: f64) -> u8 {
+            (x.clamp(0.0, 1.0) * 255.0).round() as u8
+        }
+        Color(q(r), q(g), q(b))
+    }
+
+    /// Perceptual distance to another color, as CIEDE2000.
+    pub fn delta_e(self, other: Color) -> f64 {
+        delta_e2000(Lab::from(self), Lab::from(other))
+    }
+}
+
+/// Accepts hex (`#abc`, `#aabbcc`, `0xaabbcc`), the CSS functions `rgb()`,
+/// `hsl()`, `hwb()` and `oklch()`, color names, and emoji like 🍊. Alpha is
+/// accepted but ignored, since role colors are always opaque.
+impl str::FromStr for Color {
+    type Err = Compat<Error>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse(s).map_err(Error::compat)
+    }
+}
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+impl From<Color> for SColour {
+    fn from(c: Color) -> Self {
+        SColour::from_rgb(c.0, c.1, c.2)
+    }
+}
+impl From<SColour> for Color {
+    fn from(c: SColour) -> Self {
+        Color(c.r(), c.g(), c.b())
+    }
+}
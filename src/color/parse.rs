@@ -0,0 +1,256 @@
+use std::f64::consts::PI;
+
+use failure::Error;
+use regex::Regex;
+
+use super::{emoji, Color, Hsl, Hsv, Oklch, Rgb};
+use names;
+
+pub fn parse(s: &str) -> Result<Color, Error> {
+    lazy_static! {
+        static ref HEX_RE: Regex = Regex::new(r"^(?:#|0x)?([0-9a-f]{3}|[0-9a-f]{6})$")
+            .expect("Hex color regex failed compilation");
+        static ref FN_RE: Regex = Regex::new(r"^([a-z]+)\s*\((.*)\)$")
+            .expect("Color function regex failed compilation");
+    }
+    let s = s.trim().to_lowercase();
+    let re_unwrap = "Color regex captures should always be present";
+
+    if let Some(caps) = HEX_RE.captures(&s) {
+        return parse_hex(caps.get(1).expect(re_unwrap).as_str());
+    }
+    if let Some(caps) = FN_RE.captures(&s) {
+        let name = caps.get(1).expect(re_unwrap).as_str();
+        let args = caps.get(2).expect(re_unwrap).as_str();
+        return parse_function(name, args).map_err(|e| format_err!("In `{}`: {}", s, e));
+    }
+    names::lookup(&s)
+        .map(|n| n.color)
+        .or_else(|| emoji::lookup(&s))
+        .ok_or_else(|| {
+            format_err!("\"{}\" is not a hex color, color function, color name, or emoji", s)
+        })
+}
+
+fn parse_hex(hex: &str) -> Result<Color, Error> {
+    let digit = |i: usize| {
+        u8::from_str_radix(&hex[i..i + 1], 16).map_err(|e| format_err!("Hex parsing error: {}", e))
+    };
+    if hex.len() == 3 {
+        // #abc is shorthand for #aabbcc.
+        Ok(Color(digit(0)? * 0x11, digit(1)? * 0x11, digit(2)? * 0x11))
+    } else {
+        let byte = |i: usize| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|e| format_err!("Hex parsing error: {}", e))
+        };
+        Ok(Color(byte(0)?, byte(2)?, byte(4)?))
+    }
+}
+
+fn parse_function(name: &str, args: &str) -> Result<Color, Error> {
+    let c = components(args)?;
+    match name {
+        "rgb" | "rgba" => {
+            let channel = |s: &str| -> Result<f64, Error> {
+                match percentage(s)? {
+                    Some(p) => Ok(p),
+                    None => number(s).map(|n| n / 255.0),
+                }
+            };
+            Ok(Color::from_unit(channel(c[0])?, channel(c[1])?, channel(c[2])?))
+        }
+        "hsl" | "hsla" => Ok(Color::from(Hsl {
+            h: hue(c[0])?,
+            s: fraction(c[1])?,
+            l: fraction(c[2])?,
+        })),
+        "hwb" => Ok(Color::from(hwb_to_rgb(
+            hue(c[0])?,
+            fraction(c[1])?,
+            fraction(c[2])?,
+        ))),
+        "oklch" => {
+            let l = match percentage(c[0])? {
+                Some(p) => p,
+                None => number(c[0])?,
+            };
+            // 100% chroma is 0.4, per CSS Color 4.
+            let chroma = match percentage(c[1])? {
+                Some(p) => p * 0.4,
+                None => number(c[1])?,
+            };
+            Ok(Color::from(Oklch {
+                l,
+                c: chroma.max(0.0),
+                h: hue(c[2])?,
+            }))
+        }
+        _ => bail!("unknown color function `{}`", name),
+    }
+}
+
+/// Splits function arguments in either the modern `a b c / alpha` or the
+/// legacy `a, b, c, alpha` syntax, returning the three color components.
+fn components(args: &str) -> Result<Vec<&str>, Error> {
+    let mut halves = args.splitn(2, '/');
+    let color = halves.next().unwrap_or("");
+    let alpha = halves.next();
+    let mut c: Vec<&str> = color
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if alpha.is_none() && c.len() == 4 {
+        c.pop();
+    }
+    if c.len() != 3 {
+        bail!("expected 3 components, got {}", c.len());
+    }
+    Ok(c)
+}
+
+fn number(s: &str) -> Result<f64, Error> {
+    if s == "none" {
+        return Ok(0.0);
+    }
+    s.parse::<f64>()
+        .map_err(|_| format_err!("\"{}\" is not a number", s))
+        .and_then(|n| {
+            if n.is_finite() {
+                Ok(n)
+            } else {
+                bail!("\"{}\" is not a finite number", s)
+            }
+        })
+}
+
+/// Parses `50%` as `Some(0.5)`, or returns `None` if there's no `%`.
+fn percentage(s: &str) -> Result<Option<f64>, Error> {
+    match s.strip_suffix('%') {
+        Some(n) => number(n).map(|n| Some(n / 100.0)),
+        None => Ok(None),
+    }
+}
+
+/// Saturation, lightness, whiteness and blackness are percentages, though
+/// bare numbers on the same 0–100 scale are accepted too.
+fn fraction(s: &str) -> Result<f64, Error> {
+    match percentage(s)? {
+        Some(p) => Ok(p),
+        None => number(s).map(|n| n / 100.0),
+    }
+    .map(|f| f.clamp(0.0, 1.0))
+}
+
+/// Parses a CSS `<hue>` into degrees in `0.0..360.0`.
+fn hue(s: &str) -> Result<f64, Error> {
+    let units: [(&str, f64); 4] = [
+        ("grad", 0.9),
+        ("turn", 360.0),
+        ("deg", 1.0),
+        ("rad", 180.0 / PI),
+    ];
+    let degrees = match units
+        .iter()
+        .filter_map(|&(unit, scale)| s.strip_suffix(unit).map(|n| (n, scale)))
+        .next()
+    {
+        Some((n, scale)) => number(n)? * scale,
+        None => number(s)?,
+    };
+    Ok(degrees.rem_euclid(360.0))
+}
+
+fn hwb_to_rgb(h: f64, w: f64, b: f64) -> Rgb {
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return Rgb {
+            r: gray,
+            g: gray,
+            b: gray,
+        };
+    }
+    // HWB is HSV with whiteness and blackness in place of saturation and value.
+    let v = 1.0 - b;
+    Rgb::from(Hsv {
+        h,
+        s: 1.0 - w / v,
+        v,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> String {
+        match parse(s) {
+            Ok(c) => format!("{}", c),
+            Err(e) => panic!("{:?} didn't parse: {}", s, e),
+        }
+    }
+
+    #[test]
+    fn hex_forms() {
+        assert_eq!(hex("#abc"), "aabbcc");
+        assert_eq!(hex("abc"), "aabbcc");
+        assert_eq!(hex("#FF8800"), "ff8800");
+        assert_eq!(hex("0xff8800"), "ff8800");
+        assert_eq!(hex("  #00ff00 "), "00ff00");
+        assert!(parse("#abcd").is_err());
+        assert!(parse("#ggg").is_err());
+    }
+
+    #[test]
+    fn rgb_function() {
+        assert_eq!(hex("rgb(255 0 0)"), "ff0000");
+        assert_eq!(hex("rgb(255, 128, 0)"), "ff8000");
+        assert_eq!(hex("rgb(100%, 0%, 0%)"), "ff0000");
+        assert_eq!(hex("RGB(0% 50% 100%)"), "0080ff");
+        // Alpha is accepted and ignored.
+        assert_eq!(hex("rgba(0, 0, 255, 0.5)"), "0000ff");
+        assert_eq!(hex("rgb(0 255 0 / 50%)"), "00ff00");
+        // Out of range channels are clamped.
+        assert_eq!(hex("rgb(300 -20 0)"), "ff0000");
+    }
+
+    #[test]
+    fn hsl_and_hwb_functions() {
+        assert_eq!(hex("hsl(120, 100%, 50%)"), "00ff00");
+        assert_eq!(hex("hsl(240deg 100% 50%)"), "0000ff");
+        assert_eq!(hex("hsl(0.5turn 100% 25%)"), "008080");
+        assert_eq!(hex("hsla(-120, 100%, 50%, 0.3)"), "0000ff");
+        assert_eq!(hex("hwb(0 0% 0%)"), "ff0000");
+        assert_eq!(hex("hwb(120 20% 20%)"), "33cc33");
+        // Whiteness and blackness past 100% together make a gray.
+        assert_eq!(hex("hwb(0 100% 100%)"), "808080");
+    }
+
+    #[test]
+    fn oklch_function() {
+        assert_eq!(hex("oklch(0.62796 0.25768 29.2339)"), "ff0000");
+        assert_eq!(hex("oklch(62.796% 64.42% 29.2339)"), "ff0000");
+        assert_eq!(hex("oklch(100% 0.3 0)"), "ffffff");
+        assert_eq!(hex("oklch(0% 0.3 0)"), "000000");
+    }
+
+    #[test]
+    fn oklch_outside_srgb_is_mapped_into_it() {
+        // Far more chroma than sRGB can show, so chroma is reduced while
+        // lightness and hue stay about the same.
+        let c = parse("oklch(70% 0.4 150)").unwrap();
+        let lch = Oklch::from(c);
+        assert!((lch.l - 0.7).abs() < 0.02, "{:?}", lch);
+        assert!((lch.h - 150.0).abs() < 3.0, "{:?}", lch);
+        assert!(lch.c < 0.4, "{:?}", lch);
+    }
+
+    #[test]
+    fn bad_functions_are_errors() {
+        assert!(parse("rgb(1 2)").is_err());
+        assert!(parse("rgb(1 2 3 4 5)").is_err());
+        assert!(parse("rgb(nan 0 0)").is_err());
+        assert!(parse("hsl(inf 0% 0%)").is_err());
+        assert!(parse("lab(50 0 0)").is_err());
+    }
+}
@@ -0,0 +1,570 @@
+// Color spaces, all using f64 components and the D65 white point. Hues are in
+// degrees in `0.0..360.0`; achromatic colors get a hue of 0.
+
+use super::Color;
+
+/// Gamma-encoded sRGB in `0.0..=1.0`, though intermediate results may fall
+/// outside that range.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+impl Rgb {
+    pub fn in_gamut(self) -> bool {
+        const EPSILON: f64 = 0.0001;
+        let ok = |x: f64| (-EPSILON..=1.0 + EPSILON).contains(&x);
+        ok(self.r) && ok(self.g) && ok(self.b)
+    }
+    pub fn clip(self) -> Rgb {
+        Rgb {
+            r: self.r.clamp(0.0, 1.0),
+            g: self.g.clamp(0.0, 1.0),
+            b: self.b.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LinearRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// CIELAB.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// CIELCh(ab), the polar form of CIELAB.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Lch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+/// HSL with saturation and lightness in `0.0..=1.0`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+/// HSV with saturation and value in `0.0..=1.0`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+fn normalize_hue(h: f64) -> f64 {
+    h.rem_euclid(360.0)
+}
+
+// Hue of a polar form, treating near-zero chroma as achromatic.
+fn polar_hue(a: f64, b: f64) -> f64 {
+    if a.abs() < 1e-9 && b.abs() < 1e-9 {
+        0.0
+    } else {
+        normalize_hue(b.atan2(a).to_degrees())
+    }
+}
+
+// 8-bit sRGB
+
+impl From<Color> for Rgb {
+    fn from(c: Color) -> Rgb {
+        Rgb {
+            r: f64::from(c.0) / 255.0,
+            g: f64::from(c.1) / 255.0,
+            b: f64::from(c.2) / 255.0,
+        }
+    }
+}
+impl From<Rgb> for Color {
+    fn from(c: Rgb) -> Color {
+        Color::from_unit(c.r, c.g, c.b)
+    }
+}
+
+// sRGB transfer function
+
+fn to_linear(c: f64) -> f64 {
+    if c.abs() <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((c.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+fn from_linear(c: f64) -> f64 {
+    if c.abs() <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * c.abs().powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+impl From<Rgb> for LinearRgb {
+    fn from(c: Rgb) -> LinearRgb {
+        LinearRgb {
+            r: to_linear(c.r),
+            g: to_linear(c.g),
+            b: to_linear(c.b),
+        }
+    }
+}
+impl From<LinearRgb> for Rgb {
+    fn from(c: LinearRgb) -> Rgb {
+        Rgb {
+            r: from_linear(c.r),
+            g: from_linear(c.g),
+            b: from_linear(c.b),
+        }
+    }
+}
+
+// CIE XYZ
+
+impl From<LinearRgb> for Xyz {
+    fn from(c: LinearRgb) -> Xyz {
+        Xyz {
+            x: 0.412_456_4 * c.r + 0.357_576_1 * c.g + 0.180_437_5 * c.b,
+            y: 0.212_672_9 * c.r + 0.715_152_2 * c.g + 0.072_175_0 * c.b,
+            z: 0.019_333_9 * c.r + 0.119_192_0 * c.g + 0.950_304_1 * c.b,
+        }
+    }
+}
+impl From<Xyz> for LinearRgb {
+    fn from(c: Xyz) -> LinearRgb {
+        LinearRgb {
+            r: 3.240_454_2 * c.x - 1.537_138_5 * c.y - 0.498_531_4 * c.z,
+            g: -0.969_266_0 * c.x + 1.876_010_8 * c.y + 0.041_556_0 * c.z,
+            b: 0.055_643_4 * c.x - 0.204_025_9 * c.y + 1.057_225_2 * c.z,
+        }
+    }
+}
+
+// CIELAB and CIELCh
+
+const WHITE: Xyz = Xyz {
+    x: 0.950_47,
+    y: 1.0,
+    z: 1.088_83,
+};
+const LAB_EPSILON: f64 = 216.0 / 24_389.0;
+const LAB_KAPPA: f64 = 24_389.0 / 27.0;
+
+impl From<Xyz> for Lab {
+    fn from(c: Xyz) -> Lab {
+        let f = |t: f64| {
+            if t > LAB_EPSILON {
+                t.cbrt()
+            } else {
+                (LAB_KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(c.x / WHITE.x), f(c.y / WHITE.y), f(c.z / WHITE.z));
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+impl From<Lab> for Xyz {
+    fn from(c: Lab) -> Xyz {
+        let fy = (c.l + 16.0) / 116.0;
+        let fx = fy + c.a / 500.0;
+        let fz = fy - c.b / 200.0;
+        let f_inv = |t: f64| {
+            if t.powi(3) > LAB_EPSILON {
+                t.powi(3)
+            } else {
+                (116.0 * t - 16.0) / LAB_KAPPA
+            }
+        };
+        let y = if c.l > LAB_KAPPA * LAB_EPSILON {
+            fy.powi(3)
+        } else {
+            c.l / LAB_KAPPA
+        };
+        Xyz {
+            x: f_inv(fx) * WHITE.x,
+            y: y * WHITE.y,
+            z: f_inv(fz) * WHITE.z,
+        }
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(c: Lab) -> Lch {
+        Lch {
+            l: c.l,
+            c: c.a.hypot(c.b),
+            h: polar_hue(c.a, c.b),
+        }
+    }
+}
+impl From<Lch> for Lab {
+    fn from(c: Lch) -> Lab {
+        let h = c.h.to_radians();
+        Lab {
+            l: c.l,
+            a: c.c * h.cos(),
+            b: c.c * h.sin(),
+        }
+    }
+}
+
+// OKLab and OKLCh
+
+impl From<LinearRgb> for Oklab {
+    fn from(c: LinearRgb) -> Oklab {
+        let l = 0.412_221_470_8 * c.r + 0.536_332_536_3 * c.g + 0.051_445_992_9 * c.b;
+        let m = 0.211_903_498_2 * c.r + 0.680_699_545_1 * c.g + 0.107_396_956_6 * c.b;
+        let s = 0.088_302_461_9 * c.r + 0.281_718_837_6 * c.g + 0.629_978_700_5 * c.b;
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+        Oklab {
+            l: 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+            a: 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+            b: 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+        }
+    }
+}
+impl From<Oklab> for LinearRgb {
+    fn from(c: Oklab) -> LinearRgb {
+        let l = c.l + 0.396_337_777_4 * c.a + 0.215_803_757_3 * c.b;
+        let m = c.l - 0.105_561_345_8 * c.a - 0.063_854_172_8 * c.b;
+        let s = c.l - 0.089_484_177_5 * c.a - 1.291_485_548_0 * c.b;
+        let (l, m, s) = (l.powi(3), m.powi(3), s.powi(3));
+        LinearRgb {
+            r: 4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+            g: -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+            b: -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(c: Oklab) -> Oklch {
+        Oklch {
+            l: c.l,
+            c: c.a.hypot(c.b),
+            h: polar_hue(c.a, c.b),
+        }
+    }
+}
+impl From<Oklch> for Oklab {
+    fn from(c: Oklch) -> Oklab {
+        let h = c.h.to_radians();
+        Oklab {
+            l: c.l,
+            a: c.c * h.cos(),
+            b: c.c * h.sin(),
+        }
+    }
+}
+
+impl Oklch {
+    /// Converts to sRGB, reducing chroma until the color fits the gamut
+    /// using the CSS Color 4 gamut mapping algorithm.
+    pub fn map_to_gamut(self) -> Rgb {
+        const JND: f64 = 0.02;
+        const EPSILON: f64 = 0.0001;
+
+        if self.l >= 1.0 {
+            return Rgb {
+                r: 1.0,
+                g: 1.0,
+                b: 1.0,
+            };
+        }
+        if self.l <= 0.0 {
+            return Rgb {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+            };
+        }
+        let with_chroma = |c: f64| Oklch { c, ..self };
+        // An (unclipped) color, its clipped sRGB, and the OKLab distance between them.
+        let clip = |current: Oklch| {
+            let clipped = Rgb::from(current).clip();
+            let e = super::delta_eok(Oklab::from(current), Oklab::from(clipped));
+            (clipped, e)
+        };
+
+        let rgb = Rgb::from(self);
+        if rgb.in_gamut() {
+            return rgb;
+        }
+        let (mut clipped, e) = clip(self);
+        if e < JND {
+            return clipped;
+        }
+
+        let (mut min, mut max) = (0.0, self.c);
+        let mut min_in_gamut = true;
+        while max - min > EPSILON {
+            let chroma = (min + max) / 2.0;
+            let current = with_chroma(chroma);
+            if min_in_gamut && Rgb::from(current).in_gamut() {
+                min = chroma;
+                continue;
+            }
+            let (new_clipped, e) = clip(current);
+            clipped = new_clipped;
+            if e < JND {
+                if JND - e < EPSILON {
+                    break;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        clipped
+    }
+}
+
+// HSL and HSV
+
+fn rgb_hue(c: Rgb, max: f64, delta: f64) -> f64 {
+    if delta == 0.0 {
+        0.0
+    } else if max == c.r {
+        normalize_hue(60.0 * ((c.g - c.b) / delta))
+    } else if max == c.g {
+        60.0 * ((c.b - c.r) / delta + 2.0)
+    } else {
+        60.0 * ((c.r - c.g) / delta + 4.0)
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(c: Rgb) -> Hsl {
+        let max = c.r.max(c.g).max(c.b);
+        let min = c.r.min(c.g).min(c.b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl {
+            h: rgb_hue(c, max, delta),
+            s,
+            l,
+        }
+    }
+}
+impl From<Hsl> for Rgb {
+    fn from(c: Hsl) -> Rgb {
+        let h = normalize_hue(c.h);
+        let f = |n: f64| {
+            let k = (n + h / 30.0) % 12.0;
+            let a = c.s * c.l.min(1.0 - c.l);
+            c.l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Rgb {
+            r: f(0.0),
+            g: f(8.0),
+            b: f(4.0),
+        }
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(c: Rgb) -> Hsv {
+        let max = c.r.max(c.g).max(c.b);
+        let min = c.r.min(c.g).min(c.b);
+        let delta = max - min;
+        Hsv {
+            h: rgb_hue(c, max, delta),
+            s: if max == 0.0 { 0.0 } else { delta / max },
+            v: max,
+        }
+    }
+}
+impl From<Hsv> for Rgb {
+    fn from(c: Hsv) -> Rgb {
+        let h = normalize_hue(c.h);
+        let f = |n: f64| {
+            let k = (n + h / 60.0) % 6.0;
+            c.v - c.v * c.s * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        Rgb {
+            r: f(5.0),
+            g: f(3.0),
+            b: f(1.0),
+        }
+    }
+}
+
+// Composite conversions, so any space converts to and from `Rgb` and `Color`.
+
+macro_rules! via {
+    ($from:ty => $via:ty => $to:ty) => {
+        impl From<$from> for $to {
+            fn from(c: $from) -> $to {
+                <$to>::from(<$via>::from(c))
+            }
+        }
+    };
+}
+
+via!(Rgb => LinearRgb => Xyz);
+via!(Xyz => LinearRgb => Rgb);
+via!(Rgb => Xyz => Lab);
+via!(Lab => Xyz => Rgb);
+via!(Rgb => Lab => Lch);
+via!(Lch => Lab => Rgb);
+via!(Rgb => LinearRgb => Oklab);
+via!(Oklab => LinearRgb => Rgb);
+via!(Rgb => Oklab => Oklch);
+via!(Oklch => Oklab => Rgb);
+
+via!(Color => Rgb => LinearRgb);
+via!(Color => Rgb => Xyz);
+via!(Color => Rgb => Lab);
+via!(Color => Rgb => Lch);
+via!(Color => Rgb => Oklab);
+via!(Color => Rgb => Oklch);
+via!(Color => Rgb => Hsl);
+via!(Color => Rgb => Hsv);
+
+via!(LinearRgb => Rgb => Color);
+via!(Xyz => Rgb => Color);
+via!(Lab => Rgb => Color);
+via!(Lch => Rgb => Color);
+via!(Oklab => Oklch => Color);
+via!(Hsl => Rgb => Color);
+via!(Hsv => Rgb => Color);
+
+impl From<Oklch> for Color {
+    fn from(c: Oklch) -> Color {
+        Color::from(c.map_to_gamut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(x: f64, y: f64, tolerance: f64) -> bool {
+        (x - y).abs() <= tolerance
+    }
+
+    // Every 15th level of each channel, plus the extremes.
+    fn grid() -> Vec<Color> {
+        let levels = (0..=17).map(|i| i * 15).collect::<Vec<u8>>();
+        let mut out = Vec::new();
+        for &r in &levels {
+            for &g in &levels {
+                for &b in &levels {
+                    out.push(Color(r, g, b));
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn known_values() {
+        let red = Color(0xff, 0, 0);
+        let lab = Lab::from(red);
+        assert!(close(lab.l, 53.2408, 1e-3) && close(lab.a, 80.0925, 1e-3), "{:?}", lab);
+        assert!(close(lab.b, 67.2032, 1e-3), "{:?}", lab);
+        let ok = Oklab::from(red);
+        assert!(close(ok.l, 0.62796, 1e-4) && close(ok.a, 0.22486, 1e-4), "{:?}", ok);
+        assert!(close(ok.b, 0.12585, 1e-4), "{:?}", ok);
+        let white = Xyz::from(Color(0xff, 0xff, 0xff));
+        assert!(close(white.x, 0.95047, 1e-4), "{:?}", white);
+        assert!(close(white.y, 1.0, 1e-4) && close(white.z, 1.08883, 1e-3), "{:?}", white);
+        let hsl = Hsl::from(Color(0, 0x80, 0x80));
+        assert!(close(hsl.h, 180.0, 1e-9) && close(hsl.s, 1.0, 1e-9), "{:?}", hsl);
+        let hsv = Hsv::from(Color(0xff, 0x80, 0));
+        assert!(close(hsv.h, 30.1176, 1e-3) && close(hsv.v, 1.0, 1e-9), "{:?}", hsv);
+    }
+
+    #[test]
+    fn grays_have_no_chroma() {
+        for v in 0..=255u8 {
+            let gray = Color(v, v, v);
+            assert!(Lch::from(gray).c < 1e-3, "{}", gray);
+            assert!(Oklch::from(gray).c < 1e-4, "{}", gray);
+            assert_eq!(Hsl::from(gray).s, 0.0);
+        }
+    }
+
+    #[test]
+    fn round_trips_through_every_space() {
+        for c in grid() {
+            assert_eq!(Color::from(LinearRgb::from(c)), c);
+            assert_eq!(Color::from(Xyz::from(c)), c);
+            assert_eq!(Color::from(Lab::from(c)), c);
+            assert_eq!(Color::from(Lch::from(c)), c);
+            assert_eq!(Color::from(Oklab::from(c)), c);
+            assert_eq!(Color::from(Oklch::from(c)), c);
+            assert_eq!(Color::from(Hsl::from(c)), c);
+            assert_eq!(Color::from(Hsv::from(c)), c);
+        }
+    }
+
+    #[test]
+    fn hues_are_normalized() {
+        for c in grid() {
+            for &h in &[Lch::from(c).h, Oklch::from(c).h, Hsl::from(c).h, Hsv::from(c).h] {
+                assert!((0.0..360.0).contains(&h), "{} has hue {}", c, h);
+            }
+        }
+    }
+
+    #[test]
+    fn gamut_mapping_stays_in_gamut() {
+        for h in 0..36 {
+            for l in 1..10 {
+                let lch = Oklch {
+                    l: f64::from(l) / 10.0,
+                    c: 0.4,
+                    h: f64::from(h) * 10.0,
+                };
+                let rgb = lch.map_to_gamut();
+                assert!(rgb.in_gamut(), "{:?} mapped to {:?}", lch, rgb);
+                // Lightness is kept, give or take what clipping costs.
+                assert!(close(Oklab::from(rgb).l, lch.l, 0.05), "{:?} -> {:?}", lch, rgb);
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+// WCAG 2 contrast between role colors and Discord's chat backgrounds.
+
+use color::{Color, LinearRgb, Oklch};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Theme {
+    Dark,
+    Light,
+    Amoled,
+}
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::Amoled];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::Amoled => "amoled",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Theme> {
+        let name = name.trim().to_lowercase();
+        Theme::ALL.iter().cloned().find(|t| t.name() == name)
+    }
+    /// The chat background usernames are drawn on.
+    pub fn background(self) -> Color {
+        match self {
+            Theme::Dark => Color(0x36, 0x39, 0x3f),
+            Theme::Light => Color(0xff, 0xff, 0xff),
+            Theme::Amoled => Color(0x00, 0x00, 0x00),
+        }
+    }
+    /// The color of uncolored message text.
+    pub fn text(self) -> Color {
+        match self {
+            Theme::Dark | Theme::Amoled => Color(0xdc, 0xdd, 0xde),
+            Theme::Light => Color(0x2e, 0x33, 0x38),
+        }
+    }
+}
+
+/// What `%color set` does with colors below the minimum contrast.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Policy {
+    Off,
+    Warn,
+    Reject,
+    Adjust,
+}
+impl Policy {
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::Off => "off",
+            Policy::Warn => "warn",
+            Policy::Reject => "reject",
+            Policy::Adjust => "adjust",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Policy> {
+        match name.trim().to_lowercase().as_ref() {
+            "off" => Some(Policy::Off),
+            "warn" => Some(Policy::Warn),
+            "reject" => Some(Policy::Reject),
+            "adjust" => Some(Policy::Adjust),
+            _ => None,
+        }
+    }
+}
+
+/// WCAG relative luminance.
+pub fn luminance(c: Color) -> f64 {
+    let c = LinearRgb::from(c);
+    0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b
+}
+
+/// WCAG contrast ratio, from 1 (none) to 21 (black on white).
+pub fn ratio(a: Color, b: Color) -> f64 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The themes `color` falls below `min` contrast on, with their ratios.
+pub fn failures(color: Color, themes: &[Theme], min: f64) -> Vec<(Theme, f64)> {
+    themes
+        .iter()
+        .map(|&t| (t, ratio(color, t.background())))
+        .filter(|&(_, r)| r < min)
+        .collect()
+}
+
+/// Moves `color`'s OKLCh lightness as little as possible until it meets
+/// `min` on every theme, keeping its hue and, where the gamut allows, its
+/// chroma. Returns `None` if no lightness works.
+pub fn adjust(color: Color, themes: &[Theme], min: f64) -> Option<Color> {
+    const STEP: f64 = 0.0025;
+    let lch = Oklch::from(color);
+    let readable = |c: Color| failures(c, themes, min).is_empty();
+    if readable(color) {
+        return Some(color);
+    }
+    (1..=(1.0 / STEP) as u32)
+        .flat_map(|i| {
+            let d = f64::from(i) * STEP;
+            vec![lch.l + d, lch.l - d]
+        })
+        .filter(|l| (0.0..=1.0).contains(l))
+        .map(|l| Color::from(Oklch { l, ..lch }))
+        .find(|&c| readable(c))
+}
+
+/// Describes failures like "1.2:1 on dark and 1.1:1 on amoled".
+pub fn describe(failures: &[(Theme, f64)]) -> String {
+    failures
+        .iter()
+        .map(|&(t, r)| format!("{:.1}:1 on {}", r, t.name()))
+        .collect::<Vec<_>>()
+        .join(" and ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Color = Color(0, 0, 0);
+    const WHITE: Color = Color(0xff, 0xff, 0xff);
+
+    #[test]
+    fn ratio_extremes() {
+        assert!((ratio(BLACK, WHITE) - 21.0).abs() < 1e-9);
+        assert!((ratio(WHITE, WHITE) - 1.0).abs() < 1e-9);
+        assert!((ratio(Color(0x12, 0x34, 0x56), Color(0x12, 0x34, 0x56)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ratio_is_symmetric() {
+        let colors = [BLACK, WHITE, Color(0xdd, 0x2e, 0x44), Color(0x36, 0x39, 0x3f)];
+        for &a in &colors {
+            for &b in &colors {
+                assert_eq!(ratio(a, b), ratio(b, a));
+            }
+        }
+    }
+
+    #[test]
+    fn known_ratios() {
+        // #777 on white is the classic just-under-4.5:1 gray.
+        assert!((ratio(Color(0x77, 0x77, 0x77), WHITE) - 4.48).abs() < 0.01);
+        assert!((ratio(Color(0xff, 0, 0), WHITE) - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn failures_name_the_themes() {
+        let fails = failures(Color(0x10, 0x10, 0x10), &Theme::ALL, 3.0);
+        let themes = fails.iter().map(|&(t, _)| t).collect::<Vec<_>>();
+        assert_eq!(themes, vec![Theme::Dark, Theme::Amoled]);
+        assert!(failures(Color(0x10, 0x10, 0x10), &[Theme::Light], 3.0).is_empty());
+    }
+
+    #[test]
+    fn adjust_lifts_dark_colors() {
+        let themes = [Theme::Dark, Theme::Light];
+        let dark = Color(0x10, 0x10, 0x10);
+        assert!(!failures(dark, &themes, 3.0).is_empty());
+        let adjusted = adjust(dark, &themes, 3.0).unwrap();
+        assert!(failures(adjusted, &themes, 3.0).is_empty(), "#{}", adjusted);
+        assert!(Oklch::from(adjusted).l > Oklch::from(dark).l);
+    }
+
+    #[test]
+    fn adjust_keeps_readable_colors() {
+        let color = Color(0xf4, 0x90, 0x0c);
+        assert!(failures(color, &[Theme::Dark], 3.0).is_empty());
+        assert_eq!(adjust(color, &[Theme::Dark], 3.0), Some(color));
+    }
+
+    #[test]
+    fn adjust_gives_up_on_impossible_minimums() {
+        // Nothing has 10:1 on both black and white.
+        assert_eq!(adjust(Color(0x80, 0x80, 0x80), &[Theme::Amoled, Theme::Light], 10.0), None);
+    }
+}
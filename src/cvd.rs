@@ -0,0 +1,118 @@
+// Color vision deficiency simulation, using the full-severity matrices from
+// Machado, Oliveira and Fernandes, "A Physiologically-based Model for
+// Simulation of Color Vision Deficiency" (2009), applied in linear RGB.
+
+use color::{Color, LinearRgb};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Deficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+impl Deficiency {
+    pub const ALL: [Deficiency; 3] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Deficiency::Protanopia => "protanopia",
+            Deficiency::Deuteranopia => "deuteranopia",
+            Deficiency::Tritanopia => "tritanopia",
+        }
+    }
+
+    fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// Roughly how `color` looks to someone with this deficiency.
+    pub fn simulate(self, color: Color) -> Color {
+        let c = LinearRgb::from(color);
+        let m = self.matrix();
+        let row = |r: [f64; 3]| r[0] * c.r + r[1] * c.g + r[2] * c.b;
+        Color::from(LinearRgb {
+            r: row(m[0]),
+            g: row(m[1]),
+            b: row(m[2]),
+        })
+    }
+}
+
+/// Who could mistake one color for another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Confusion {
+    /// The colors look alike even without a deficiency.
+    Everyone,
+    /// The colors only look alike with one of these deficiencies.
+    With(Vec<Deficiency>),
+}
+
+/// Whether `a` and `b` are within `distance` (CIEDE2000) of each other, and
+/// if not, under which deficiencies they are.
+pub fn confusion(a: Color, b: Color, distance: f64) -> Option<Confusion> {
+    if a.delta_e(b) < distance {
+        return Some(Confusion::Everyone);
+    }
+    let deficiencies = Deficiency::ALL
+        .iter()
+        .cloned()
+        .filter(|d| d.simulate(a).delta_e(d.simulate(b)) < distance)
+        .collect::<Vec<_>>();
+    if deficiencies.is_empty() {
+        None
+    } else {
+        Some(Confusion::With(deficiencies))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similar_colors_look_alike_to_everyone() {
+        let a = Color(0x80, 0x40, 0x40);
+        assert_eq!(confusion(a, a, 5.0), Some(Confusion::Everyone));
+        assert_eq!(confusion(a, Color(0x82, 0x40, 0x40), 5.0), Some(Confusion::Everyone));
+    }
+
+    #[test]
+    fn simulated_lookalikes_are_confusions() {
+        // Red next to how it looks without red or green cones, which the
+        // simulation leaves (almost) unchanged when applied again.
+        let red = Color(0xff, 0, 0);
+        for &d in &[Deficiency::Protanopia, Deficiency::Deuteranopia] {
+            let seen = d.simulate(red);
+            assert!(red.delta_e(seen) >= 5.0, "{}", d.name());
+            match confusion(red, seen, 5.0) {
+                Some(Confusion::With(ds)) => assert!(ds.contains(&d), "{}", d.name()),
+                other => panic!("{}: {:?}", d.name(), other),
+            }
+        }
+    }
+
+    #[test]
+    fn distinct_colors_arent_confusions() {
+        assert_eq!(confusion(Color(0, 0, 0), Color(0xff, 0xff, 0xff), 5.0), None);
+    }
+}
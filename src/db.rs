@@ -14,6 +14,26 @@ pub fn open(path: &Path) -> Result<Option<Box<Cdb>>, Error> {
     }
 }
 
+fn rm_tmp(name: &str, tmp_path: &Path) -> Result<(), Error> {
+    if tmp_path.exists() {
+        fs::remove_file(tmp_path)
+            .map_err(|e| format_err!("Couldn't remove old tmp {} DB: {}", name, e))
+    } else {
+        Ok(())
+    }
+}
+fn set<C, F, T>(name: &str, path: &Path, tmp_path: &Path, creator: C, f: F) -> Result<T, Error>
+where
+    C: FnMut(&mut CdbCreator),
+    F: FnOnce(Box<Cdb>) -> T,
+{
+    let out = Cdb::new(tmp_path, creator)
+        .map(f)
+        .map_err(|e| format_err!("Error creating {} DB: {:?}", name, e))?;
+    fs::rename(tmp_path, path).map_err(|e| format_err!("Couldn't replace old {} DB: {}", name, e))?;
+    Ok(out)
+}
+
 pub fn data(guild: &str) -> PathBuf {
     PathBuf::from(format!("./data/{}", guild))
 }
@@ -28,24 +48,40 @@ pub fn ensure_dir(dir: &Path) -> Result<(), Error> {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Guild {
     Colors,
+    Names,
+    Palette,
+    Reserved,
+    Settings,
     Users,
 }
 impl Guild {
     pub fn name(self) -> &'static str {
         match self {
             Guild::Colors => "colors",
+            Guild::Names => "names",
+            Guild::Palette => "palette",
+            Guild::Reserved => "reserved",
+            Guild::Settings => "settings",
             Guild::Users => "users",
         }
     }
     pub fn path(self, guild: &Path) -> PathBuf {
         match self {
             Guild::Colors => guild.join("colors.cdb"),
+            Guild::Names => guild.join("names.cdb"),
+            Guild::Palette => guild.join("palette.cdb"),
+            Guild::Reserved => guild.join("reserved.cdb"),
+            Guild::Settings => guild.join("settings.cdb"),
             Guild::Users => guild.join("users.cdb"),
         }
     }
     pub fn tmp_path(self, guild: &Path) -> PathBuf {
         match self {
             Guild::Colors => guild.join("colors.cdb.tmp"),
+            Guild::Names => guild.join("names.cdb.tmp"),
+            Guild::Palette => guild.join("palette.cdb.tmp"),
+            Guild::Reserved => guild.join("reserved.cdb.tmp"),
+            Guild::Settings => guild.join("settings.cdb.tmp"),
             Guild::Users => guild.join("users.cdb.tmp"),
         }
     }
@@ -53,25 +89,49 @@ impl Guild {
         open(&self.path(guild))
     }
     pub fn rm_tmp(self, guild: &Path) -> Result<(), Error> {
-        let tmp_path = self.tmp_path(guild);
-        if tmp_path.exists() {
-            fs::remove_file(&tmp_path)
-                .map_err(|e| format_err!("Couldn't remove old tmp {} DB: {}", self.name(), e))
-        } else {
-            Ok(())
-        }
+        rm_tmp(self.name(), &self.tmp_path(guild))
     }
     pub fn set<C, F, T>(self, guild: &Path, creator: C, f: F) -> Result<T, Error>
     where
         C: FnMut(&mut CdbCreator),
         F: FnOnce(Box<Cdb>) -> T,
     {
-        let tmp_path = self.tmp_path(guild);
-        let out = Cdb::new(&tmp_path, creator)
-            .map(f)
-            .map_err(|e| format_err!("Error creating {} DB: {:?}", self.name(), e))?;
-        fs::rename(&tmp_path, &self.path(guild))
-            .map_err(|e| format_err!("Couldn't replace old {} DB: {}", self.name(), e))?;
-        Ok(out)
+        set(self.name(), &self.path(guild), &self.tmp_path(guild), creator, f)
+    }
+}
+
+/// DBs shared by every guild, kept in `./data/` beside the guild directories.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Global {
+    Favorites,
+}
+impl Global {
+    pub fn name(self) -> &'static str {
+        match self {
+            Global::Favorites => "favorites",
+        }
+    }
+    pub fn path(self) -> PathBuf {
+        match self {
+            Global::Favorites => PathBuf::from("./data/favorites.cdb"),
+        }
+    }
+    pub fn tmp_path(self) -> PathBuf {
+        match self {
+            Global::Favorites => PathBuf::from("./data/favorites.cdb.tmp"),
+        }
+    }
+    pub fn open(self) -> Result<Option<Box<Cdb>>, Error> {
+        open(&self.path())
+    }
+    pub fn rm_tmp(self) -> Result<(), Error> {
+        rm_tmp(self.name(), &self.tmp_path())
+    }
+    pub fn set<C, F, T>(self, creator: C, f: F) -> Result<T, Error>
+    where
+        C: FnMut(&mut CdbCreator),
+        F: FnOnce(Box<Cdb>) -> T,
+    {
+        set(self.name(), &self.path(), &self.tmp_path(), creator, f)
     }
 }
@@ -0,0 +1,152 @@
+// Color names admins have defined for their guild, stored in `names.cdb` as
+// names mapped to hex colors. They're looked up before the built-in names,
+// so a guild can have its own "brand" or even its own "red".
+
+use std::path::Path;
+use std::str;
+
+use failure::Error;
+
+use color::Color;
+use db;
+use names;
+
+/// The longest name allowed, so lists and previews stay readable.
+pub const MAX_NAME: usize = 32;
+
+// Words commands read before looking up a color, which a definition could
+// never be reached by: `%color set me`, and the schemes `%color harmony`
+// takes as its last argument.
+const RESERVED: &[&str] = &["me", "complementary", "analogous", "triadic", "tetradic"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition {
+    pub name: String,
+    pub color: Color,
+}
+
+// Lowercased with runs of whitespace collapsed, like the built-in names.
+fn key(name: &str) -> String {
+    name.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Checks that `name` can be defined, returning the form it's stored under.
+/// Names are letters, digits, spaces, `-` and `_`, and can't be read as hex,
+/// since hex colors always mean themselves.
+pub fn validate(name: &str) -> Result<String, Error> {
+    let name = key(name);
+    if name.is_empty() {
+        bail!("Give a name to define.");
+    }
+    if name.chars().count() > MAX_NAME {
+        bail!("Names can be at most {} characters.", MAX_NAME);
+    }
+    if !name.chars().all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_') {
+        bail!("Names can only have letters, digits, spaces, `-` and `_`.");
+    }
+    if name.parse::<Color>().is_ok() && names::lookup(&name).is_none() {
+        bail!("\"{}\" is already a hex color.", name);
+    }
+    if RESERVED.contains(&name.as_str()) {
+        bail!("\"{}\" already means something to commands, so it can't be a name.", name);
+    }
+    Ok(name)
+}
+
+pub fn load(data: &Path) -> Result<Vec<Definition>, Error> {
+    let mut definitions = Vec::new();
+    if let Some(mut db) = db::Guild::Names.open(data)? {
+        for (k, v) in db.iter() {
+            let name = str::from_utf8(k)
+                .map_err(|e| format_err!("Defined color name wasn't UTF-8: {}", e))?;
+            let color = str::from_utf8(v)
+                .ok()
+                .and_then(|s| s.parse::<Color>().ok())
+                .ok_or_else(|| format_err!("Color for defined name \"{}\" is invalid.", name))?;
+            definitions.push(Definition {
+                name: name.to_string(),
+                color,
+            });
+        }
+    }
+    definitions.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(definitions)
+}
+
+pub fn store(data: &Path, definitions: &[Definition]) -> Result<(), Error> {
+    db::ensure_dir(data)?;
+    db::Guild::Names.rm_tmp(data)?;
+    db::Guild::Names.set(
+        data,
+        |ndb| {
+            for d in definitions {
+                let _ = ndb.add(d.name.as_bytes(), format!("{}", d.color).as_bytes());
+            }
+        },
+        |_| (),
+    )
+}
+
+/// The definition for `name`, ignoring case and extra spaces.
+pub fn lookup<'a>(definitions: &'a [Definition], name: &str) -> Option<&'a Definition> {
+    let name = key(name);
+    definitions.iter().find(|d| d.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_normalized() {
+        assert_eq!(validate("  Team   Red ").unwrap(), "team red");
+        assert_eq!(validate("brand_2-dark").unwrap(), "brand_2-dark");
+    }
+
+    #[test]
+    fn bad_names_are_errors() {
+        assert!(validate("").is_err());
+        assert!(validate("   ").is_err());
+        assert!(validate(&"a".repeat(MAX_NAME + 1)).is_err());
+        assert!(validate(&"a".repeat(MAX_NAME)).is_ok());
+        assert!(validate("brand!").is_err());
+        assert!(validate("#brand").is_err());
+    }
+
+    #[test]
+    fn hex_colors_cant_be_names() {
+        assert!(validate("c0392b").is_err());
+        assert!(validate("fff").is_err());
+        assert!(validate("decade").is_err());
+        assert!(validate("cafe au lait").is_ok());
+    }
+
+    #[test]
+    fn reserved_words_cant_be_names() {
+        for &word in RESERVED {
+            assert!(validate(word).is_err(), "{}", word);
+        }
+        assert!(validate(" ME ").is_err());
+        assert!(validate("me too").is_ok());
+    }
+
+    #[test]
+    fn lookup_ignores_case_and_spacing() {
+        let definitions = vec![
+            Definition {
+                name: String::from("brand"),
+                color: Color(0x58, 0x65, 0xf2),
+            },
+            Definition {
+                name: String::from("team red"),
+                color: Color(0xc0, 0x39, 0x2b),
+            },
+        ];
+        assert_eq!(lookup(&definitions, "Brand"), Some(&definitions[0]));
+        assert_eq!(lookup(&definitions, " TEAM  red "), Some(&definitions[1]));
+        assert_eq!(lookup(&definitions, "team"), None);
+    }
+}
@@ -0,0 +1,199 @@
+// Finding the main colors of an image with k-means clustering in OKLab, where
+// distances roughly match how different colors look.
+
+use std::cmp::Ordering;
+
+use failure::Error;
+
+use color::{Color, Oklab};
+use fetch::Fetch;
+use image::{self, Image};
+
+/// Pixels sampled from large images; more barely changes the result.
+const SAMPLES: usize = 10_000;
+/// Clusters to find. More than are returned, so small details and
+/// anti-aliasing between areas get clusters of their own instead of
+/// dragging the big ones toward them.
+const CLUSTERS: usize = 8;
+const ITERATIONS: usize = 20;
+/// Returned colors are at least this far apart, in CIEDE2000.
+const SPACING: f64 = 8.0;
+
+fn distance(a: &Oklab, b: &Oklab) -> f64 {
+    (a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)
+}
+
+fn closest(p: &Oklab, centers: &[Oklab]) -> usize {
+    (0..centers.len())
+        .min_by(|&i, &j| {
+            distance(p, &centers[i])
+                .partial_cmp(&distance(p, &centers[j]))
+                .unwrap_or(Ordering::Equal)
+        })
+        .expect("There's always a center")
+}
+
+/// Up to `n` of the most common colors in `image`, most common first, with
+/// the share of the image's opaque pixels each one covers. Mostly transparent
+/// pixels are left out, so a logo's background doesn't count.
+pub fn dominant(image: &Image, n: usize) -> Vec<(Color, f64)> {
+    let opaque = image
+        .rgba
+        .chunks(4)
+        .filter(|p| p[3] >= 0x80)
+        .collect::<Vec<_>>();
+    if opaque.is_empty() {
+        return Vec::new();
+    }
+    let step = opaque.len().div_ceil(SAMPLES);
+    let points = opaque
+        .iter()
+        .step_by(step)
+        .map(|p| Oklab::from(Color(p[0], p[1], p[2])))
+        .collect::<Vec<_>>();
+
+    // Farthest-point seeding: start from the first sample, then keep adding
+    // whichever sample is farthest from every center so far. It's
+    // deterministic, so the same image always gives the same colors.
+    let mut centers = vec![points[0]];
+    let mut nearest = points
+        .iter()
+        .map(|p| distance(p, &points[0]))
+        .collect::<Vec<_>>();
+    while centers.len() < CLUSTERS {
+        let (far, &d) = nearest
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(Ordering::Equal))
+            .expect("There's always a point");
+        if d == 0.0 {
+            // Fewer distinct colors than clusters.
+            break;
+        }
+        let center = points[far];
+        for (d, p) in nearest.iter_mut().zip(&points) {
+            *d = d.min(distance(p, &center));
+        }
+        centers.push(center);
+    }
+
+    let mut assignment = vec![0; points.len()];
+    for _ in 0..ITERATIONS {
+        let mut changed = false;
+        for (a, p) in assignment.iter_mut().zip(&points) {
+            let c = closest(p, &centers);
+            if *a != c {
+                *a = c;
+                changed = true;
+            }
+        }
+
+        let mut sums = vec![(0.0, 0.0, 0.0, 0usize); centers.len()];
+        for (&a, p) in assignment.iter().zip(&points) {
+            let s = &mut sums[a];
+            s.0 += p.l;
+            s.1 += p.a;
+            s.2 += p.b;
+            s.3 += 1;
+        }
+        for (center, &(l, a, b, count)) in centers.iter_mut().zip(&sums) {
+            if count > 0 {
+                let count = count as f64;
+                *center = Oklab {
+                    l: l / count,
+                    a: a / count,
+                    b: b / count,
+                };
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut counts = vec![0usize; centers.len()];
+    for &a in &assignment {
+        counts[a] += 1;
+    }
+    let mut clusters = centers
+        .into_iter()
+        .zip(counts)
+        .filter(|&(_, count)| count > 0)
+        .map(|(center, count)| (Color::from(center), count as f64 / points.len() as f64))
+        .collect::<Vec<_>>();
+    clusters.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    // A near-duplicate of a bigger cluster is added to it instead, so its
+    // share still counts.
+    let mut out: Vec<(Color, f64)> = Vec::with_capacity(n);
+    for (color, share) in clusters {
+        match out.iter_mut().find(|o| o.0.delta_e(color) < SPACING) {
+            Some(o) => o.1 += share,
+            None => out.push((color, share)),
+        }
+    }
+    out.truncate(n);
+    out
+}
+
+/// Downloads the image at `url` with `fetch` and finds its `n` main colors.
+pub fn from_url<F: Fetch>(fetch: &F, url: &str, n: usize) -> Result<Vec<(Color, f64)>, Error> {
+    let image = image::decode(&fetch.fetch(url)?)?;
+    let colors = dominant(&image, n);
+    if colors.is_empty() {
+        bail!("That image is entirely transparent.");
+    }
+    Ok(colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use image::tests::fixture;
+
+    /// Serves fixtures by URL, like a CDN that only has a few files.
+    struct Stub(HashMap<&'static str, Vec<u8>>);
+    impl Fetch for Stub {
+        fn fetch(&self, url: &str) -> Result<Vec<u8>, Error> {
+            self.0
+                .get(url)
+                .cloned()
+                .ok_or_else(|| format_err!("Couldn't download the image: 404 Not Found"))
+        }
+    }
+
+    fn stub() -> Stub {
+        let mut files = HashMap::new();
+        files.insert("https://cdn/red-and-blue.png", fixture("red-and-blue.png"));
+        files.insert("https://cdn/transparent.png", fixture("transparent.png"));
+        files.insert("https://cdn/not-an-image.txt", b"Hello!".to_vec());
+        Stub(files)
+    }
+
+    #[test]
+    fn main_colors_with_their_shares() {
+        // Three quarters red, one quarter blue.
+        let colors = from_url(&stub(), "https://cdn/red-and-blue.png", 5).unwrap();
+        assert_eq!(colors.len(), 2);
+        assert!(colors[0].0.delta_e(Color(0xff, 0, 0)) < 1.0);
+        assert!((colors[0].1 - 0.75).abs() < 1e-9);
+        assert!(colors[1].0.delta_e(Color(0, 0, 0xff)) < 1.0);
+        assert!((colors[1].1 - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fewer_colors_can_be_asked_for() {
+        let colors = from_url(&stub(), "https://cdn/red-and-blue.png", 1).unwrap();
+        assert_eq!(colors.len(), 1);
+        assert!(colors[0].0.delta_e(Color(0xff, 0, 0)) < 1.0);
+    }
+
+    #[test]
+    fn errors_are_passed_on() {
+        assert!(from_url(&stub(), "https://cdn/transparent.png", 5).is_err());
+        assert!(from_url(&stub(), "https://cdn/not-an-image.txt", 5).is_err());
+        assert!(from_url(&stub(), "https://cdn/missing.png", 5).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// Colors members have saved for themselves, shared across every guild. Stored
+// in `./data/favorites.cdb` as user IDs mapped to lines of `<hex> <name>`.
+
+use std::path::Path;
+use std::str;
+
+use failure::Error;
+use serenity::model::id::UserId;
+use serenity::prelude::Mutex;
+
+use color::Color;
+use db;
+
+/// How many colors one person can save.
+pub const MAX: usize = 25;
+/// The longest name allowed, so lists stay readable.
+pub const MAX_NAME: usize = 32;
+
+lazy_static! {
+    // Everyone's favorites share one file, so changes are made one at a time
+    // to keep two people saving at once from losing either's.
+    static ref WRITING: Mutex<()> = Mutex::new(());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Favorite {
+    pub name: String,
+    pub color: Color,
+}
+
+/// Checks that `name` can be saved under, returning the form it's stored as.
+/// Names are recalled as `@name`, so they're a single word of letters,
+/// digits, `-` and `_`.
+pub fn validate(name: &str) -> Result<String, Error> {
+    let name = name.trim().trim_start_matches('@').to_lowercase();
+    if name.is_empty() {
+        bail!("Give a name to save the color under.");
+    }
+    if name.chars().count() > MAX_NAME {
+        bail!("Names can be at most {} characters.", MAX_NAME);
+    }
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+        bail!("Names can only have letters, digits, `-` and `_`.");
+    }
+    Ok(name)
+}
+
+fn parse(value: &[u8]) -> Result<Vec<Favorite>, Error> {
+    let value = str::from_utf8(value).map_err(|e| format_err!("Favorites weren't UTF-8: {}", e))?;
+    value
+        .lines()
+        .map(|line| {
+            let mut parts = line.splitn(2, ' ');
+            let color = parts.next().and_then(|hex| hex.parse::<Color>().ok());
+            match (color, parts.next()) {
+                (Some(color), Some(name)) => Ok(Favorite {
+                    name: name.to_string(),
+                    color,
+                }),
+                _ => Err(format_err!("Favorite `{}` is invalid.", line)),
+            }
+        })
+        .collect()
+}
+
+fn format(favorites: &[Favorite]) -> String {
+    favorites
+        .iter()
+        .map(|f| format!("{} {}", f.color, f.name))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `user`'s favorites, in the order they were saved.
+pub fn load(user: UserId) -> Result<Vec<Favorite>, Error> {
+    match db::Global::Favorites.open()? {
+        Some(mut db) => match db.find(format!("{}", user).as_bytes()) {
+            Some(value) => parse(value),
+            None => Ok(Vec::new()),
+        },
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Changes `user`'s favorites with `f`, saving them if it succeeds. No other
+/// change can happen in between.
+pub fn update<F, T>(user: UserId, f: F) -> Result<T, Error>
+where
+    F: FnOnce(&mut Vec<Favorite>) -> Result<T, Error>,
+{
+    let _writing = WRITING.lock();
+    let mut favorites = load(user)?;
+    let result = f(&mut favorites)?;
+    store(user, &favorites)?;
+    Ok(result)
+}
+
+// Replaces `user`'s favorites, leaving everyone else's alone. Only call this
+// while holding `WRITING`.
+fn store(user: UserId, favorites: &[Favorite]) -> Result<(), Error> {
+    let user_str = format!("{}", user);
+    let mut everyone = Vec::new();
+    if let Some(mut db) = db::Global::Favorites.open()? {
+        for (k, v) in db.iter() {
+            if k != user_str.as_bytes() {
+                everyone.push((k.to_vec(), v.to_vec()));
+            }
+        }
+    }
+    if !favorites.is_empty() {
+        everyone.push((user_str.into_bytes(), format(favorites).into_bytes()));
+    }
+
+    db::ensure_dir(Path::new("./data"))?;
+    db::Global::Favorites.rm_tmp()?;
+    db::Global::Favorites.set(
+        |ndb| {
+            for (k, v) in &everyone {
+                let _ = ndb.add(k, v);
+            }
+        },
+        |_| (),
+    )
+}
+
+/// Adds `favorite` to `saved`, replacing and returning any with its name, as
+/// long as there's a free slot.
+pub fn add(saved: &mut Vec<Favorite>, favorite: Favorite) -> Result<Option<Favorite>, Error> {
+    let replaced = saved
+        .iter()
+        .position(|f| f.name == favorite.name)
+        .map(|i| saved.remove(i));
+    if replaced.is_none() && saved.len() >= MAX {
+        bail!("You can save at most {} colors; `%color forget` one first.", MAX);
+    }
+    saved.push(favorite);
+    Ok(replaced)
+}
+
+/// The favorite called `name`, with or without its `@`.
+pub fn lookup<'a>(favorites: &'a [Favorite], name: &str) -> Option<&'a Favorite> {
+    let name = name.trim().trim_start_matches('@').to_lowercase();
+    favorites.iter().find(|f| f.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn favorite(name: &str, color: Color) -> Favorite {
+        Favorite {
+            name: name.to_string(),
+            color,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let favorites = vec![
+            favorite("sunset", Color(0xf4, 0x90, 0x0c)),
+            favorite("deep-sea_2", Color(0, 0x33, 0x66)),
+            favorite("ñandú", Color(0xff, 0xff, 0xff)),
+        ];
+        assert_eq!(parse(format(&favorites).as_bytes()).unwrap(), favorites);
+        assert_eq!(parse(b"").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn bad_lines_are_errors() {
+        assert!(parse(b"f4900c").is_err());
+        assert!(parse(b"nothex sunset").is_err());
+        assert!(parse(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn names_are_validated() {
+        assert_eq!(validate(" @Sunset ").unwrap(), "sunset");
+        assert!(validate("@").is_err());
+        assert!(validate("two words").is_err());
+        assert!(validate(&"a".repeat(MAX_NAME + 1)).is_err());
+    }
+
+    #[test]
+    fn slots_run_out() {
+        let mut saved = (0..MAX)
+            .map(|i| favorite(&format!("fav{}", i), Color(i as u8, 0, 0)))
+            .collect::<Vec<_>>();
+        assert!(add(&mut saved, favorite("one-more", Color(0, 0, 0))).is_err());
+        assert_eq!(saved.len(), MAX);
+
+        // Replacing one doesn't need a free slot.
+        let replaced = add(&mut saved, favorite("fav3", Color(0, 0xff, 0))).unwrap();
+        assert_eq!(replaced, Some(favorite("fav3", Color(3, 0, 0))));
+        assert_eq!(saved.len(), MAX);
+        assert_eq!(lookup(&saved, "@FAV3").unwrap().color, Color(0, 0xff, 0));
+    }
+}
@@ -0,0 +1,141 @@
+// Downloading images over HTTP. Commands take anything implementing `Fetch`
+// so they can be pointed at a local server instead of Discord's CDN.
+
+use std::io::Read;
+
+use failure::Error;
+use hyper::net::HttpsConnector;
+use hyper::Client;
+use hyper_native_tls::NativeTlsClient;
+
+/// Bodies larger than this are refused; Discord's own upload limit is 8 MiB.
+pub const MAX_SIZE: u64 = 8 * 1024 * 1024;
+
+pub trait Fetch {
+    /// The body of `url`, if it came back successfully and within `MAX_SIZE`.
+    fn fetch(&self, url: &str) -> Result<Vec<u8>, Error>;
+}
+
+/// Fetches over plain HTTP or HTTPS.
+pub struct HttpFetch {
+    client: Client,
+}
+impl HttpFetch {
+    pub fn new() -> Result<HttpFetch, Error> {
+        let tls = NativeTlsClient::new().map_err(|e| format_err!("Couldn't set up TLS: {}", e))?;
+        Ok(HttpFetch {
+            client: Client::with_connector(HttpsConnector::new(tls)),
+        })
+    }
+}
+impl Fetch for HttpFetch {
+    fn fetch(&self, url: &str) -> Result<Vec<u8>, Error> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .map_err(|e| format_err!("Couldn't download the image: {}", e))?;
+        if !response.status.is_success() {
+            bail!("Couldn't download the image: {}", response.status);
+        }
+        let mut body = Vec::new();
+        response
+            .take(MAX_SIZE + 1)
+            .read_to_end(&mut body)
+            .map_err(|e| format_err!("Couldn't download the image: {}", e))?;
+        if body.len() as u64 > MAX_SIZE {
+            bail!("That image is over {} MiB.", MAX_SIZE / 1024 / 1024);
+        }
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::thread;
+
+    use super::*;
+    use image::tests::fixture;
+
+    /// Serves `tests/fixtures` over plain HTTP on a free local port, standing
+    /// in for Discord's CDN. `/big` is a body one byte over `MAX_SIZE`, and
+    /// anything missing is a 404.
+    fn serve() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                if reader.read_line(&mut request).is_err() {
+                    continue;
+                }
+                // Skip the headers; the path is all that matters.
+                let mut line = String::new();
+                while reader.read_line(&mut line).map(|n| n > 2).unwrap_or(false) {
+                    line.clear();
+                }
+
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+                let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+                let (status, body) = if path == "/big" {
+                    ("200 OK", vec![0; MAX_SIZE as usize + 1])
+                } else {
+                    match fs::read(fixtures.join(path.trim_start_matches('/'))) {
+                        Ok(body) => ("200 OK", body),
+                        Err(_) => ("404 Not Found", b"Not found".to_vec()),
+                    }
+                };
+                let head = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                // The client hangs up early on bodies that are too big.
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(&body);
+            }
+        });
+        format!("http://{}", address)
+    }
+
+    // Plain HTTP, since the stand-in has no certificate.
+    fn fetch() -> HttpFetch {
+        HttpFetch {
+            client: Client::new(),
+        }
+    }
+
+    #[test]
+    fn fetches_images() {
+        let server = serve();
+        let body = fetch()
+            .fetch(&format!("{}/red-and-blue.png", server))
+            .unwrap();
+        assert!(body == fixture("red-and-blue.png"));
+    }
+
+    #[test]
+    fn errors_are_refused() {
+        let server = serve();
+        let err = fetch()
+            .fetch(&format!("{}/missing.png", server))
+            .unwrap_err();
+        assert!(err.to_string().contains("404"), "{}", err);
+    }
+
+    #[test]
+    fn big_bodies_are_refused() {
+        let server = serve();
+        let err = fetch().fetch(&format!("{}/big", server)).unwrap_err();
+        assert!(err.to_string().contains("over 8 MiB"), "{}", err);
+    }
+}
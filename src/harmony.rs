@@ -0,0 +1,123 @@
+// Color harmonies, rotating hue in OKLCh so companions keep the base color's
+// perceived lightness and chroma.
+
+use color::{Color, Oklch};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scheme {
+    Complementary,
+    Analogous,
+    Triadic,
+    Tetradic,
+}
+impl Scheme {
+    pub const ALL: [Scheme; 4] = [
+        Scheme::Complementary,
+        Scheme::Analogous,
+        Scheme::Triadic,
+        Scheme::Tetradic,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scheme::Complementary => "complementary",
+            Scheme::Analogous => "analogous",
+            Scheme::Triadic => "triadic",
+            Scheme::Tetradic => "tetradic",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Scheme> {
+        let name = name.trim().to_lowercase();
+        Scheme::ALL.iter().cloned().find(|s| s.name() == name)
+    }
+
+    /// Hue offsets of the companion colors, in degrees.
+    pub fn offsets(self) -> &'static [f64] {
+        match self {
+            Scheme::Complementary => &[180.0],
+            Scheme::Analogous => &[-30.0, 30.0],
+            Scheme::Triadic => &[120.0, 240.0],
+            // The rectangle: two complementary pairs 60° apart.
+            Scheme::Tetradic => &[60.0, 180.0, 240.0],
+        }
+    }
+}
+
+/// Below this OKLCh chroma a color is gray, and turning its hue does nothing.
+pub const MIN_CHROMA: f64 = 0.02;
+
+/// The companions of `color` in `scheme`, with their hue offsets. Grays have
+/// no hue to turn, so they have none.
+pub fn companions(color: Color, scheme: Scheme) -> Vec<(f64, Color)> {
+    let lch = Oklch::from(color);
+    if lch.c < MIN_CHROMA {
+        return Vec::new();
+    }
+    scheme
+        .offsets()
+        .iter()
+        .map(|&offset| {
+            let h = (lch.h + offset).rem_euclid(360.0);
+            (offset, Color::from(Oklch { h, ..lch }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hue_difference(a: f64, b: f64) -> f64 {
+        let d = (a - b).rem_euclid(360.0);
+        d.min(360.0 - d)
+    }
+
+    #[test]
+    fn companions_turn_the_hue() {
+        // Low enough chroma that every rotation stays in gamut.
+        let base = Oklch {
+            l: 0.7,
+            c: 0.1,
+            h: 30.0,
+        };
+        let color = Color::from(base);
+        let base = Oklch::from(color);
+        let cases: [(Scheme, &[f64]); 4] = [
+            (Scheme::Complementary, &[180.0]),
+            (Scheme::Analogous, &[-30.0, 30.0]),
+            (Scheme::Triadic, &[120.0, 240.0]),
+            (Scheme::Tetradic, &[60.0, 180.0, 240.0]),
+        ];
+        for &(scheme, offsets) in &cases {
+            let companions = companions(color, scheme);
+            assert_eq!(
+                companions.iter().map(|&(o, _)| o).collect::<Vec<_>>(),
+                offsets,
+                "{}",
+                scheme.name()
+            );
+            for &(offset, c) in &companions {
+                let lch = Oklch::from(c);
+                let expected = (base.h + offset).rem_euclid(360.0);
+                assert!(hue_difference(lch.h, expected) < 1.0, "{} {}", scheme.name(), offset);
+                assert!((lch.l - base.l).abs() < 0.01);
+                assert!((lch.c - base.c).abs() < 0.01);
+            }
+        }
+    }
+
+    #[test]
+    fn grays_have_no_companions() {
+        for &gray in &[Color(0, 0, 0), Color(0x80, 0x80, 0x80), Color(0xff, 0xff, 0xff)] {
+            for &scheme in &Scheme::ALL {
+                assert!(companions(gray, scheme).is_empty(), "#{}", gray);
+            }
+        }
+    }
+
+    #[test]
+    fn schemes_by_name() {
+        assert_eq!(Scheme::from_name(" Triadic "), Some(Scheme::Triadic));
+        assert_eq!(Scheme::from_name("square"), None);
+    }
+}
@@ -0,0 +1,391 @@
+// A PNG decoder, enough to read the colors out of uploads and avatars.
+//
+// Every color type, bit depth and interlacing is handled, along with tRNS
+// transparency. Gamma, color profiles and the rest of the ancillary chunks
+// are ignored, as is the CRC, since a corrupt image just gives odd colors.
+
+use std::io::Read;
+
+use failure::Error;
+use flate2::read::ZlibDecoder;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Images with more pixels than this are refused rather than decoded.
+pub const MAX_PIXELS: u64 = 4096 * 4096;
+
+/// A decoded image, `width * height` RGBA quadruples in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+struct Header {
+    width: u32,
+    height: u32,
+    depth: u8,
+    kind: u8,
+    interlaced: bool,
+}
+impl Header {
+    fn channels(&self) -> usize {
+        match self.kind {
+            0 | 3 => 1,
+            2 => 3,
+            4 => 2,
+            _ => 4,
+        }
+    }
+    fn bits_per_pixel(&self) -> usize {
+        self.channels() * usize::from(self.depth)
+    }
+    fn stride(&self, width: u32) -> usize {
+        (width as usize * self.bits_per_pixel()).div_ceil(8)
+    }
+}
+
+pub fn decode(data: &[u8]) -> Result<Image, Error> {
+    if !data.starts_with(&SIGNATURE) {
+        bail!("Only PNG images can be read.");
+    }
+
+    let mut header = None;
+    let mut palette: Vec<[u8; 4]> = Vec::new();
+    let mut transparent: Option<Vec<u16>> = None;
+    let mut idat = Vec::new();
+
+    let mut rest = &data[SIGNATURE.len()..];
+    loop {
+        if rest.len() < 12 {
+            bail!("That PNG is cut short.");
+        }
+        let len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+        let kind = &rest[4..8];
+        let body = rest
+            .get(8..8 + len)
+            .ok_or_else(|| format_err!("That PNG is cut short."))?;
+        match kind {
+            b"IHDR" => {
+                if len != 13 {
+                    bail!("That PNG has a broken header.");
+                }
+                let h = Header {
+                    width: u32::from_be_bytes([body[0], body[1], body[2], body[3]]),
+                    height: u32::from_be_bytes([body[4], body[5], body[6], body[7]]),
+                    depth: body[8],
+                    kind: body[9],
+                    interlaced: body[12] == 1,
+                };
+                let valid = match h.kind {
+                    0 => [1, 2, 4, 8, 16].contains(&h.depth),
+                    3 => [1, 2, 4, 8].contains(&h.depth),
+                    2 | 4 | 6 => [8, 16].contains(&h.depth),
+                    _ => false,
+                };
+                if !valid || body[10] != 0 || body[11] != 0 || body[12] > 1 {
+                    bail!("That PNG uses a format that doesn't exist.");
+                }
+                if h.width == 0 || h.height == 0 {
+                    bail!("That PNG is empty.");
+                }
+                if u64::from(h.width) * u64::from(h.height) > MAX_PIXELS {
+                    bail!("That image is too big; keep it under 4096×4096.");
+                }
+                header = Some(h);
+            }
+            b"PLTE" => {
+                palette = body
+                    .chunks(3)
+                    .filter(|c| c.len() == 3)
+                    .map(|c| [c[0], c[1], c[2], 0xff])
+                    .collect();
+            }
+            b"tRNS" => match header.as_ref().map(|h| h.kind) {
+                Some(3) => {
+                    for (entry, &alpha) in palette.iter_mut().zip(body) {
+                        entry[3] = alpha;
+                    }
+                }
+                Some(0) | Some(2) => {
+                    transparent = Some(
+                        body.chunks(2)
+                            .filter(|c| c.len() == 2)
+                            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                            .collect(),
+                    );
+                }
+                _ => {}
+            },
+            b"IDAT" => idat.extend_from_slice(body),
+            b"IEND" => break,
+            _ => {}
+        }
+        rest = rest
+            .get(12 + len..)
+            .ok_or_else(|| format_err!("That PNG is cut short."))?;
+    }
+
+    let header = header.ok_or_else(|| format_err!("That PNG has no header."))?;
+    if header.kind == 3 && palette.is_empty() {
+        bail!("That PNG has no palette.");
+    }
+
+    // Each pass of an interlaced image is its own little image with a filter
+    // byte per row; a plain image is just one pass covering everything.
+    let passes: &[(u32, u32, u32, u32)] = if header.interlaced {
+        &[
+            (0, 0, 8, 8),
+            (4, 0, 8, 8),
+            (0, 4, 4, 8),
+            (2, 0, 4, 4),
+            (0, 2, 2, 4),
+            (1, 0, 2, 2),
+            (0, 1, 1, 2),
+        ]
+    } else {
+        &[(0, 0, 1, 1)]
+    };
+    let sizes = passes
+        .iter()
+        .map(|&(x0, y0, dx, dy)| {
+            let w = (header.width + dx - 1 - x0) / dx;
+            let h = (header.height + dy - 1 - y0) / dy;
+            if w == 0 || h == 0 {
+                (w, h, 0)
+            } else {
+                (w, h, (header.stride(w) + 1) * h as usize)
+            }
+        })
+        .collect::<Vec<_>>();
+    let expected = sizes.iter().map(|&(_, _, len)| len).sum::<usize>();
+
+    let mut raw = Vec::with_capacity(expected);
+    ZlibDecoder::new(&idat[..])
+        .take(expected as u64)
+        .read_to_end(&mut raw)
+        .map_err(|e| format_err!("That PNG's image data is corrupt: {}", e))?;
+    if raw.len() < expected {
+        bail!("That PNG is cut short.");
+    }
+
+    let mut rgba = vec![0; header.width as usize * header.height as usize * 4];
+    let mut offset = 0;
+    for (&(x0, y0, dx, dy), &(w, h, len)) in passes.iter().zip(&sizes) {
+        if len == 0 {
+            continue;
+        }
+        let pass = &mut raw[offset..offset + len];
+        offset += len;
+        unfilter(pass, header.stride(w), header.bits_per_pixel().div_ceil(8))?;
+
+        let stride = header.stride(w) + 1;
+        for y in 0..h {
+            let row = &pass[y as usize * stride + 1..(y as usize + 1) * stride];
+            for x in 0..w {
+                let pixel = read_pixel(&header, &palette, transparent.as_ref(), row, x as usize);
+                let i = (((y0 + y * dy) * header.width + x0 + x * dx) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&pixel);
+            }
+        }
+    }
+
+    Ok(Image {
+        width: header.width,
+        height: header.height,
+        rgba,
+    })
+}
+
+/// Undoes the per-row filters in place, leaving each row's filter byte alone.
+fn unfilter(data: &mut [u8], stride: usize, bpp: usize) -> Result<(), Error> {
+    let rows = data.len() / (stride + 1);
+    for y in 0..rows {
+        let (above, current) = data.split_at_mut(y * (stride + 1));
+        let prior = if y == 0 {
+            None
+        } else {
+            Some(&above[above.len() - stride..])
+        };
+        let (&mut filter, row) = current[..stride + 1]
+            .split_first_mut()
+            .expect("Rows have a filter byte");
+        if filter > 4 {
+            bail!("That PNG's image data is corrupt.");
+        }
+        for i in 0..stride {
+            let a = if i >= bpp { row[i - bpp] } else { 0 };
+            let b = prior.map_or(0, |p| p[i]);
+            let c = match prior {
+                Some(p) if i >= bpp => p[i - bpp],
+                _ => 0,
+            };
+            row[i] = row[i].wrapping_add(match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((u16::from(a) + u16::from(b)) / 2) as u8,
+                _ => paeth(a, b, c),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = i16::from(a) + i16::from(b) - i16::from(c);
+    let (pa, pb, pc) = (
+        (p - i16::from(a)).abs(),
+        (p - i16::from(b)).abs(),
+        (p - i16::from(c)).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// The `index`th sample of `row`, at its original depth.
+fn sample(row: &[u8], depth: u8, index: usize) -> u16 {
+    match depth {
+        16 => u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]),
+        8 => u16::from(row[index]),
+        _ => {
+            let depth = usize::from(depth);
+            let bit = index * depth;
+            let shift = 8 - depth - bit % 8;
+            u16::from(row[bit / 8] >> shift) & ((1 << depth) - 1)
+        }
+    }
+}
+
+fn read_pixel(
+    header: &Header,
+    palette: &[[u8; 4]],
+    transparent: Option<&Vec<u16>>,
+    row: &[u8],
+    x: usize,
+) -> [u8; 4] {
+    let channels = header.channels();
+    let raw = (0..channels)
+        .map(|c| sample(row, header.depth, x * channels + c))
+        .collect::<Vec<_>>();
+    if header.kind == 3 {
+        return palette
+            .get(raw[0] as usize)
+            .cloned()
+            .unwrap_or([0, 0, 0, 0xff]);
+    }
+
+    let max = (1u32 << header.depth) - 1;
+    let scale = |v: u16| (u32::from(v) * 255 / max) as u8;
+    let opaque = match transparent {
+        Some(t) if t.len() == channels => t[..] != raw[..],
+        _ => true,
+    };
+    let alpha = |v: Option<u16>| match v {
+        Some(v) => scale(v),
+        None if opaque => 0xff,
+        None => 0,
+    };
+    match header.kind {
+        0 => [scale(raw[0]), scale(raw[0]), scale(raw[0]), alpha(None)],
+        2 => [scale(raw[0]), scale(raw[1]), scale(raw[2]), alpha(None)],
+        4 => [
+            scale(raw[0]),
+            scale(raw[0]),
+            scale(raw[0]),
+            alpha(Some(raw[1])),
+        ],
+        _ => [
+            scale(raw[0]),
+            scale(raw[1]),
+            scale(raw[2]),
+            alpha(Some(raw[3])),
+        ],
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+    use color::Color;
+    use render::Canvas;
+
+    /// The bytes of `tests/fixtures/<name>`.
+    pub fn fixture(name: &str) -> Vec<u8> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name);
+        fs::read(&path).unwrap_or_else(|e| panic!("Couldn't read {}: {}", path.display(), e))
+    }
+
+    // Each fixture is 13×7 of random samples, with the image data split over
+    // two IDAT chunks and every filter type used. The `.rgba` files were
+    // written alongside them by the script that made them.
+    #[test]
+    fn fixtures() {
+        let names = [
+            "gray1-interlaced",
+            "gray16-trns",
+            "rgb8",
+            "rgb16-interlaced",
+            "palette2-trns",
+            "palette8-interlaced",
+            "gray-alpha8",
+            "rgba8-interlaced",
+            "rgba16",
+        ];
+        for name in &names {
+            let image = decode(&fixture(&format!("{}.png", name)))
+                .unwrap_or_else(|e| panic!("{}: {}", name, e));
+            assert_eq!((image.width, image.height), (13, 7), "{}", name);
+            assert!(
+                image.rgba == fixture(&format!("{}.rgba", name)),
+                "{} decoded wrong",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn canvas_round_trip() {
+        let mut canvas = Canvas::new(20, 10, Color(0x12, 0x34, 0x56));
+        canvas.fill(5, 2, 10, 6, Color(0xfe, 0xdc, 0xba));
+        let image = decode(&canvas.png()).unwrap();
+        assert_eq!((image.width, image.height), (20, 10));
+        for y in 0..10 {
+            for x in 0..20 {
+                let i = (y * 20 + x) * 4;
+                let expected = if (5..15).contains(&x) && (2..8).contains(&y) {
+                    [0xfe, 0xdc, 0xba, 0xff]
+                } else {
+                    [0x12, 0x34, 0x56, 0xff]
+                };
+                assert_eq!(image.rgba[i..i + 4], expected, "({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn truncated_pngs_are_errors() {
+        let png = fixture("rgb8.png");
+        for len in 0..png.len() {
+            assert!(decode(&png[..len]).is_err(), "{} bytes", len);
+        }
+    }
+
+    #[test]
+    fn other_formats_are_errors() {
+        assert!(decode(b"GIF89a").is_err());
+        assert!(decode(&[0xff, 0xd8, 0xff, 0xe0]).is_err());
+        assert!(decode(b"").is_err());
+    }
+}
@@ -1,39 +1,72 @@
 extern crate dotenv;
 #[macro_use]
 extern crate failure;
+extern crate flate2;
+extern crate hyper;
+extern crate hyper_native_tls;
 #[macro_use]
 extern crate lazy_static;
 extern crate memchr;
+extern crate rand;
 extern crate regex;
+extern crate serde_json;
 extern crate serenity;
 extern crate tinycdb;
 extern crate typemap;
 
 use std::env;
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
-use std::fmt;
+use std::path::Path;
 use std::str;
 use std::sync::Arc;
 
 use dotenv::dotenv;
-use failure::{Compat, Error};
-use regex::Regex;
-use serenity::builder::CreateMessage;
+use failure::Error;
+use rand::Rng;
+use serenity::builder::{CreateEmbed, CreateMessage};
 use serenity::client::{Client, Context, EventHandler};
 use serenity::client::bridge::gateway::{ShardId, ShardManager};
 use serenity::framework::standard::{help_commands, DispatchError, HelpBehaviour, StandardFramework};
 use serenity::http;
-use serenity::model::channel::{Channel, Message};
+use serenity::model::channel::{Attachment, Channel, Message, Reaction};
 use serenity::model::gateway::{Game, Ready};
-use serenity::model::id::{RoleId, UserId};
+use serenity::model::guild::{Guild, Role};
+use serenity::model::id::{ChannelId, RoleId, UserId};
+use serenity::model::user::User;
+use serenity::model::misc::Mentionable;
 use serenity::model::permissions::Permissions;
-use serenity::prelude::Mutex;
+use serenity::prelude::{Mutex, RwLock};
 use serenity::utils::Colour as SColour;
+use tinycdb::Cdb;
 use typemap::Key;
 
+mod choices;
+mod color;
+mod contrast;
+mod cvd;
 mod db;
+mod defined;
+mod dominant;
+mod favorites;
+mod fetch;
+mod harmony;
+mod image;
+mod mix;
+mod names;
+mod pager;
+mod palette;
+mod render;
+mod reserved;
+mod settings;
+mod suggest;
 mod util;
 
+use color::{Color, Hsl, Hsv, Oklch};
+use contrast::Policy as ContrastPolicy;
+use cvd::Confusion;
+use palette::{Fit, Mode as PaletteMode};
+use settings::Settings;
 use util::{Args, CmdFn};
 
 struct ShardManagerContainer;
@@ -47,6 +80,17 @@ impl EventHandler for Handler {
     fn ready(&self, _: Context, ready: Ready) {
         println!("{} is connected!", ready.user.name);
     }
+
+    fn reaction_add(&self, ctx: Context, reaction: Reaction) {
+        pager::on_reaction(&ctx, &reaction);
+        if let Some(color) = choices::chosen(&ctx, &reaction) {
+            if let Err(e) = pick_color(&reaction, color) {
+                let _ = reaction
+                    .channel_id
+                    .say(format!("{}: Error: {}", reaction.user_id.mention(), e));
+            }
+        }
+    }
 }
 
 // If main is so good, why haven't they made a main 2?
@@ -114,10 +158,55 @@ fn main2() -> Result<(), Error> {
                     .guild_only(true)
                     .command("set", |c| c.cmd(CmdFn(color_set)))
                     .command("unset", |c| c.cmd(CmdFn(color_unset)))
+                    .command("preview", |c| c.cmd(CmdFn(color_preview)))
+                    .command("info", |c| c.cmd(CmdFn(color_info)))
+                    .command("list", |c| c.cmd(CmdFn(color_list)))
+                    .command("palette", |c| c.cmd(CmdFn(color_palette)))
+                    .command("suggest", |c| c.cmd(CmdFn(color_suggest)))
+                    .command("harmony", |c| c.cmd(CmdFn(color_harmony)))
+                    .command("lighter", |c| c.cmd(CmdFn(color_lighter)))
+                    .command("darker", |c| c.cmd(CmdFn(color_darker)))
+                    .command("saturate", |c| c.cmd(CmdFn(color_saturate)))
+                    .command("desaturate", |c| c.cmd(CmdFn(color_desaturate)))
+                    .command("hue", |c| c.cmd(CmdFn(color_hue)))
+                    .command("mix", |c| c.cmd(CmdFn(color_mix)))
+                    .command("gradient", |c| c.cmd(CmdFn(color_gradient)))
+                    .command("random", |c| c.cmd(CmdFn(color_random)))
+                    .command("from-image", |c| c.cmd(CmdFn(color_from_image)))
                     .command("clean", |c| {
                         c.cmd(CmdFn(color_clean))
                             .required_permissions(Permissions::ADMINISTRATOR)
                     })
+                    .command("config", |c| {
+                        c.cmd(CmdFn(color_config))
+                            .required_permissions(Permissions::ADMINISTRATOR)
+                    })
+                    .command("reserve", |c| {
+                        c.cmd(CmdFn(color_reserve))
+                            .required_permissions(Permissions::ADMINISTRATOR)
+                    })
+                    .command("unreserve", |c| {
+                        c.cmd(CmdFn(color_unreserve))
+                            .required_permissions(Permissions::ADMINISTRATOR)
+                    })
+                    .command("reserved", |c| c.cmd(CmdFn(color_reserved)))
+                    .command("define", |c| {
+                        c.cmd(CmdFn(color_define))
+                            .required_permissions(Permissions::ADMINISTRATOR)
+                    })
+                    .command("undefine", |c| {
+                        c.cmd(CmdFn(color_undefine))
+                            .required_permissions(Permissions::ADMINISTRATOR)
+                    })
+                    .command("defined", |c| c.cmd(CmdFn(color_defined)))
+            })
+            .group("Favorites", |g| {
+                // Favorites aren't tied to a guild, so these work in DMs too.
+                g.prefix("color")
+                    .bucket("color")
+                    .command("save", |c| c.cmd(CmdFn(color_save)))
+                    .command("forget", |c| c.cmd(CmdFn(color_forget)))
+                    .command("favorites", |c| c.cmd(CmdFn(color_favorites)))
             })
             .customised_help(help_commands::with_embeds, |c| {
                 c.lacking_permissions(HelpBehaviour::Strike)
@@ -244,60 +333,163 @@ fn quit(ctx: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
 
 // Color commands
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Color(u8, u8, u8);
-impl str::FromStr for Color {
-    type Err = Compat<Error>;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$")
-                    .expect("Color regex failed compilation");
+fn color_set(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+
+    let (flags, args) = args.split_flags();
+    let mut exact = false;
+    for flag in &flags {
+        match flag.as_ref() {
+            "exact" => exact = true,
+            _ => bail!("Unknown flag `--{}`.", flag),
+        }
+    }
+    let spec = args.join(" ");
+    // `from:<text>` and `me` hash text into a color, made readable on the
+    // guild's themes if it needs to be.
+    let text = match spec.get(..5) {
+        _ if spec == "me" => Some(msg.author.name.clone()),
+        Some(prefix) if prefix.eq_ignore_ascii_case("from:") => match spec[5..].trim() {
+            "" => bail!("Give some text after `from:`."),
+            text => Some(text.to_string()),
+        },
+        _ => None,
+    };
+    let guild_id = { guild.read().id };
+    let data = db::data(&format!("{}", guild_id));
+    let (requested, name) = match text {
+        Some(ref text) => {
+            let settings = Settings::load(&data)?;
+            let color = suggest::from_text(text);
+            let color = contrast::adjust(color, &settings.themes, settings.min_contrast)
+                .unwrap_or(color);
+            (color, None)
         }
-        let s = s.to_lowercase();
-        let caps = RE.captures(&s)
-            .ok_or_else(|| format_err!("\"{}\" is not a RGB hex color", s).compat())?;
+        None => parse_color(&data, msg.author.id, &spec)?,
+    };
 
-        let re_unwrap = "Color regex captures should always be present";
-        let r = caps.get(1).expect(re_unwrap);
-        let g = caps.get(2).expect(re_unwrap);
-        let b = caps.get(3).expect(re_unwrap);
+    let (mut reply, color, label) = set_color(&guild, msg.author.id, requested, name, exact)?;
+    if let Some(text) = text {
+        reply.push_str(&format!(" (From \"{}\".)", text));
+    }
+    send_preview(msg.channel_id, &msg.author, &reply, color, &label);
+    Ok(())
+}
 
-        let r = u8::from_str_radix(r.as_str(), 16)
-            .map_err(|e| format_err!("Red parsing error: {}", e).compat())?;
-        let g = u8::from_str_radix(g.as_str(), 16)
-            .map_err(|e| format_err!("Green parsing error: {}", e).compat())?;
-        let b = u8::from_str_radix(b.as_str(), 16)
-            .map_err(|e| format_err!("Blue parsing error: {}", e).compat())?;
+// Lightness and chroma amounts are percentages of the OKLCh ranges, where
+// 100% chroma is 0.4 as in CSS.
 
-        Ok(Color(r, g, b))
-    }
+fn color_lighter(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    set_relative(msg, args, "lighter", 10.0, |lch, n| Oklch {
+        l: (lch.l + n / 100.0).clamp(0.0, 1.0),
+        ..lch
+    })
 }
-impl fmt::Display for Color {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:02x}{:02x}{:02x}", self.0, self.1, self.2)
-    }
+
+fn color_darker(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    set_relative(msg, args, "darker", 10.0, |lch, n| Oklch {
+        l: (lch.l - n / 100.0).clamp(0.0, 1.0),
+        ..lch
+    })
+}
+
+fn color_saturate(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    set_relative(msg, args, "saturate", 10.0, |lch, n| Oklch {
+        c: (lch.c + n * 0.004).max(0.0),
+        ..lch
+    })
+}
+
+fn color_desaturate(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    set_relative(msg, args, "desaturate", 10.0, |lch, n| Oklch {
+        c: (lch.c - n * 0.004).max(0.0),
+        ..lch
+    })
 }
-impl From<Color> for SColour {
-    fn from(c: Color) -> Self {
-        SColour::from_rgb(c.0, c.1, c.2)
+
+fn color_hue(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    if args.clone().next().is_none() {
+        bail!("Give a number of degrees to turn the hue by, like `+30` or `-90`.");
     }
+    set_relative(msg, args, "hue", 0.0, |lch, n| Oklch {
+        h: (lch.h + n).rem_euclid(360.0),
+        ..lch
+    })
 }
 
-fn color_set(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
+/// Sets the author's color to `f` of their current one in OKLCh, with an
+/// amount from the arguments or `default`.
+fn set_relative<F>(msg: &Message, mut args: Args, command: &str, default: f64, f: F) -> Result<(), Error>
+where
+    F: Fn(Oklch, f64) -> Oklch,
+{
     let guild = msg.guild()
         .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let amount = match args.next() {
+        Some(arg) => arg.trim_end_matches(&['%', '°'][..])
+            .trim_end_matches("deg")
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite())
+            .ok_or_else(|| format_err!("\"{}\" isn't an amount.", arg))?,
+        None => default,
+    };
 
-    let color = args.next()
-        .ok_or_else(|| format_err!("You must provide a hex RGB color."))
-        .and_then(|a| {
-            a.parse::<Color>()
-                .map_err(|e| format_err!("Color parsing: {}", e))
-        })?;
-    let color_str = format!("{}", color);
-    let color_bytes = color_str.as_bytes();
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+    let current = member_colors(&data)?
+        .into_iter()
+        .find(|&(user, _)| user == msg.author.id)
+        .map(|(_, c)| c)
+        .ok_or_else(|| format_err!("You have no color to start from; set one first."))?;
 
-    let author_id = msg.author.id;
+    let mut target = Color::from(f(Oklch::from(current), amount));
+    // A snapping palette still applies, so the step lands on its nearest
+    // color; anything outside it is left for `set_color` to turn down.
+    let settings = Settings::load(&data)?;
+    if settings.palette_only == PaletteMode::Snap {
+        let allowed = palette::load(&data)?;
+        if let Fit::Palette(entry) = palette::fit(PaletteMode::Snap, &allowed, target, true) {
+            target = entry.color;
+        }
+    }
+    if target == current {
+        bail!("`{} {}` doesn't change #{}.", command, amount, current);
+    }
+    // Snapping to other roles could undo small steps, so that's skipped.
+    let (reply, color, label) = set_color(&guild, msg.author.id, target, None, true)?;
+    let reply = format!("{} (`{} {}` from #{}.)", reply, command, amount, current);
+    send_preview(msg.channel_id, &msg.author, &reply, color, &label);
+    Ok(())
+}
+
+/// Sets the color someone picked by reacting to an offer.
+fn pick_color(reaction: &Reaction, color: Color) -> Result<(), Error> {
+    let guild = match reaction.channel_id.find() {
+        Some(Channel::Guild(channel)) => channel.read().guild(),
+        _ => None,
+    }.ok_or_else(|| format_err!("Colors can only be picked in guilds."))?;
+    let user = reaction
+        .user()
+        .map_err(|e| format_err!("Couldn't get user: {}", e))?;
+
+    let (reply, color, label) = set_color(&guild, user.id, color, None, false)?;
+    send_preview(reaction.channel_id, &user, &reply, color, &label);
+    Ok(())
+}
+
+/// Gives a member `requested`, subject to the guild's settings, and returns
+/// the reply to send, the color they ended up with, and a label for its
+/// preview.
+fn set_color(
+    guild: &Arc<RwLock<Guild>>,
+    author_id: UserId,
+    requested: Color,
+    name: Option<String>,
+    exact: bool,
+) -> Result<(String, Color, String), Error> {
     let author_id_str = format!("{}", author_id);
     let author_id_bytes = author_id_str.as_bytes();
 
@@ -306,6 +498,7 @@ fn color_set(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error
     let data = db::data(&guild_str);
 
     db::ensure_dir(&data)?;
+    let settings = Settings::load(&data)?;
     let mut users = db::Guild::Users.open(&data)?;
 
     let old_role = users.as_mut().and_then(|db| {
@@ -315,6 +508,140 @@ fn color_set(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error
             .and_then(|id| guild.write().roles.get(&id).map(|r| r.id))
     });
     let mut colors = db::Guild::Colors.open(&data)?;
+
+    // Palette colors are used as they are, since admins picked them.
+    let allowed = if settings.palette_only == PaletteMode::Off {
+        Vec::new()
+    } else {
+        palette::load(&data)?
+    };
+    let from_palette = match palette::fit(settings.palette_only, &allowed, requested, !exact) {
+        Fit::Free => None,
+        Fit::Palette(entry) => Some(entry.color),
+        Fit::Outside(Some(entry)) => bail!(
+            "{} isn't on this server's palette; the closest is {}. See `%color palette allowed`.",
+            describe_color(requested, name.as_ref()),
+            entry
+        ),
+        Fit::Outside(None) => bail!(
+            "This server only allows colors from its palette, which is empty. \
+             Admins can add colors with `%color palette add`."
+        ),
+    };
+
+    let reservations = reserved::load(&data)?;
+    // In unique mode, everyone else's color is off limits.
+    let taken = if settings.unique > 0.0 {
+        member_colors(&data)?
+            .into_iter()
+            .filter(|&(user, _)| user != author_id)
+            .map(|(_, c)| c)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let free = |c: Color| taken.iter().all(|&t| c.delta_e(t) >= settings.unique);
+
+    let readable = |c: Color| {
+        settings.contrast == ContrastPolicy::Off
+            || contrast::failures(c, &settings.themes, settings.min_contrast).is_empty()
+    };
+    let enforce_contrast = from_palette.is_none() && match settings.contrast {
+        ContrastPolicy::Reject | ContrastPolicy::Adjust => true,
+        ContrastPolicy::Off | ContrastPolicy::Warn => false,
+    };
+
+    let adjusted = if from_palette.is_none() && settings.contrast == ContrastPolicy::Adjust
+        && !readable(requested)
+    {
+        Some(
+            contrast::adjust(requested, &settings.themes, settings.min_contrast).ok_or_else(
+                || {
+                    format_err!(
+                        "#{} can't be made readable at {}:1 on {} without changing its hue.",
+                        requested,
+                        settings.min_contrast,
+                        settings
+                            .themes
+                            .iter()
+                            .map(|t| t.name())
+                            .collect::<Vec<_>>()
+                            .join(" and ")
+                    )
+                },
+            )?,
+        )
+    } else {
+        None
+    };
+    let unsnapped = from_palette.or(adjusted).unwrap_or(requested);
+
+    let color = match colors.as_mut() {
+        Some(colors) if !exact && from_palette.is_none() && settings.snap > 0.0 => {
+            nearest_color_role(colors, &guild.read().roles, unsnapped)
+                .filter(|&(c, d)| {
+                    d <= settings.snap && (!enforce_contrast || readable(c))
+                        && reserved::conflict(&reservations, c).is_none() && free(c)
+                })
+                .map_or(unsnapped, |(c, _)| c)
+        }
+        _ => unsnapped,
+    };
+
+    if let Some((reservation, distance)) = reserved::conflict(&reservations, color) {
+        bail!(
+            "#{} is too close to the reserved color #{} (ΔE {:.1}; it needs at least {}).",
+            color,
+            reservation.color,
+            distance,
+            reservation.radius
+        );
+    }
+
+    if !free(color) {
+        let candidates = if allowed.is_empty() {
+            suggest::around(unsnapped)
+        } else {
+            allowed.iter().map(|e| e.color).collect()
+        };
+        let suggestions = suggest::nearest(unsnapped, &candidates, 3, settings.unique / 2.0, |c| {
+            free(c) && reserved::conflict(&reservations, c).is_none()
+                && (!enforce_contrast || readable(c))
+        });
+        let mut error = format!(
+            "#{} is too close to another member's color; everyone here needs a color at least ΔE {} from the rest.",
+            color, settings.unique
+        );
+        if !suggestions.is_empty() {
+            error.push_str(&format!(
+                " Some free colors nearby: {}.",
+                suggestions
+                    .iter()
+                    .map(|c| format!("`#{}`", c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        bail!(error);
+    }
+
+    let low_contrast = if settings.contrast == ContrastPolicy::Off {
+        Vec::new()
+    } else {
+        contrast::failures(color, &settings.themes, settings.min_contrast)
+    };
+    if enforce_contrast && !low_contrast.is_empty() {
+        bail!(
+            "#{} would be hard to read, with a contrast of {}. This server needs at least {}:1.",
+            color,
+            contrast::describe(&low_contrast),
+            settings.min_contrast
+        );
+    }
+
+    let color_str = format!("{}", color);
+    let color_bytes = color_str.as_bytes();
+
     let role = colors
         .as_mut()
         .and_then(|colors| {
@@ -397,11 +724,1018 @@ fn color_set(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error
         |_| (),
     )?;
 
-    let _ = msg.reply(&format!("Your color is now #{}.", color));
+    let requested_str = describe_color(requested, name.as_ref());
+    let mut reply = if color == requested {
+        format!("Your color is now {}.", requested_str)
+    } else {
+        format!("Your color is now #{}.", color)
+    };
+    if let Some(adjusted) = adjusted {
+        reply.push_str(&format!(
+            " {} was adjusted to #{} for readability.",
+            requested_str, adjusted
+        ));
+    }
+    if from_palette.is_some() && unsnapped != requested {
+        reply.push_str(&format!(
+            " That's the palette color closest to {}.",
+            requested_str
+        ));
+    }
+    if color != unsnapped {
+        reply.push_str(&format!(
+            " That's the existing color closest to {}; \
+             add `--exact` to get exactly what you asked for.",
+            if adjusted.is_some() {
+                format!("#{}", unsnapped)
+            } else {
+                requested_str.clone()
+            }
+        ));
+    }
+    if !low_contrast.is_empty() {
+        reply.push_str(&format!(
+            " Heads up: it may be hard to read, with a contrast of {} (at least {}:1 is recommended).",
+            contrast::describe(&low_contrast),
+            settings.min_contrast
+        ));
+    }
+    if settings.cvd_distance > 0.0 {
+        let lookalikes = staff_lookalikes(&guild.read().roles, color, settings.cvd_distance);
+        if !lookalikes.is_empty() {
+            reply.push_str(&format!(
+                " Heads up: it may look like {}.",
+                lookalikes.join(" and ")
+            ));
+        }
+    }
+    let label = if color == requested {
+        name.unwrap_or_default()
+    } else {
+        String::new()
+    };
+    Ok((reply, color, label))
+}
+
+fn color_preview(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let (color, name) = parse_color(&data, msg.author.id, &args.collect::<Vec<_>>().join(" "))?;
+    let label = name.clone().unwrap_or_default();
+    send_preview(
+        msg.channel_id,
+        &msg.author,
+        &format!("Here's {}.", describe_color(color, name.as_ref())),
+        color,
+        &label,
+    );
+    Ok(())
+}
+
+fn color_info(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    // Members whose color is within this ΔE count as using about the same one.
+    const NEARBY: f64 = 5.0;
+
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+    let (color, name) = parse_color(&data, msg.author.id, &args.collect::<Vec<_>>().join(" "))?;
+    let settings = Settings::load(&data)?;
+
+    let nearby = member_colors(&data)?
+        .into_iter()
+        .filter(|&(_, c)| color.delta_e(c) <= NEARBY)
+        .count();
+
+    let (hsl, hsv, oklch) = (Hsl::from(color), Hsv::from(color), Oklch::from(color));
+    let (nearest, distance) = names::nearest(color);
+    let nearest = if distance < 0.5 {
+        format!("{} (exact)", nearest)
+    } else {
+        format!("{} (#{}, ΔE {:.1})", nearest, nearest.color, distance)
+    };
+    let contrast = contrast::Theme::ALL
+        .iter()
+        .map(|&t| format!("{:.1}:1 on {}", contrast::ratio(color, t.background()), t.name()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let usage = match nearby {
+        1 => format!("1 member within ΔE {}", NEARBY),
+        n => format!("{} members within ΔE {}", n, NEARBY),
+    };
+    let lookalikes = if settings.cvd_distance > 0.0 {
+        staff_lookalikes(&guild.read().roles, color, settings.cvd_distance)
+    } else {
+        Vec::new()
+    };
+
+    let info = |e: CreateEmbed| {
+        let e = e.title(describe_color(color, name.as_ref()))
+            .colour(color)
+            .field("Hex", format!("#{}", color), true)
+            .field("RGB", format!("rgb({} {} {})", color.0, color.1, color.2), true)
+            .field(
+                "HSL",
+                format!("hsl({:.0} {:.0}% {:.0}%)", hsl.h, hsl.s * 100.0, hsl.l * 100.0),
+                true,
+            )
+            .field(
+                "HSV",
+                format!("hsv({:.0} {:.0}% {:.0}%)", hsv.h, hsv.s * 100.0, hsv.v * 100.0),
+                true,
+            )
+            .field(
+                "OKLCh",
+                format!("oklch({:.1}% {:.3} {:.1})", oklch.l * 100.0, oklch.c, oklch.h),
+                true,
+            )
+            .field("Nearest name", &nearest, true)
+            .field("Contrast", &contrast, true)
+            .field("In use", &usage, true);
+        if lookalikes.is_empty() {
+            e
+        } else {
+            e.field("Staff lookalikes", lookalikes.join("\n"), false)
+        }
+    };
+    let png = render::preview(color, "", &msg.author.name).png();
+    let sent = util::send_image(msg.channel_id, &png, "preview.png", |m| {
+        m.embed(|e| info(e).image("attachment://preview.png"))
+    });
+    if sent.is_err() {
+        let _ = msg.channel_id.send_message(|m| m.embed(info));
+    }
+    Ok(())
+}
+
+fn color_list(ctx: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
+    const PER_PAGE: usize = 15;
+
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let sort = args.next().map_or_else(|| String::from("hue"), |s| s.to_lowercase());
+
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let mut entries = color_roles(&guild.read().roles, &data)?;
+    if entries.is_empty() {
+        bail!("There are no colors for this guild.");
+    }
+
+    let sorted_by = match sort.as_ref() {
+        "hue" => {
+            sort_by_hue(&mut entries);
+            "hue"
+        }
+        "popular" | "popularity" => {
+            entries.sort_by(|a, b| b.2.cmp(&a.2).then(a.1.cmp(&b.1)));
+            "popularity"
+        }
+        "age" => {
+            // Role IDs are snowflakes, so they sort by creation time.
+            entries.sort_by_key(|e| e.1);
+            "age"
+        }
+        _ => bail!("Sort by `hue`, `popular` or `age`."),
+    };
+
+    let pages = entries
+        .chunks(PER_PAGE)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|&(color, role, count)| {
+                    format!(
+                        "`#{}` {} {} member{}",
+                        color,
+                        role.mention(),
+                        count,
+                        if count == 1 { "" } else { "s" }
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    pager::Pager::new(
+        format!("Colors ({})", entries.len()),
+        format!("sorted by {}", sorted_by),
+        pages,
+    ).send(ctx, msg.channel_id)
+}
+
+fn color_palette(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let subcommand = args.next();
+    let subcommand = subcommand.as_ref().map(|s| s.as_ref());
+    if let Some("add") | Some("remove") | Some("clear") | Some("load") = subcommand {
+        if !guild
+            .read()
+            .member_permissions(msg.author.id)
+            .administrator()
+        {
+            bail!("Only administrators can change the palette.");
+        }
+    }
+
+    let mut entries = color_roles(&guild.read().roles, &data)?;
+    match subcommand {
+        None => {
+            if entries.is_empty() {
+                bail!("There are no colors for this guild.");
+            }
+            sort_by_hue(&mut entries);
+            let swatches = entries
+                .iter()
+                .map(|&(color, _, count)| (color, count))
+                .collect::<Vec<_>>();
+            send_palette(msg, &format!("Palette ({} colors)", swatches.len()), &swatches)
+        }
+        Some("allowed") => {
+            let allowed = palette::load(&data)?;
+            if allowed.is_empty() {
+                bail!("This server has no palette. Admins can add colors with `%color palette add`.");
+            }
+            let mut swatches = allowed
+                .iter()
+                .map(|entry| {
+                    let count = entries
+                        .iter()
+                        .filter(|e| e.0 == entry.color)
+                        .map(|e| e.2)
+                        .sum();
+                    (entry.color, (), count)
+                })
+                .collect::<Vec<_>>();
+            sort_by_hue(&mut swatches);
+            let swatches = swatches
+                .into_iter()
+                .map(|(color, _, count)| (color, count))
+                .collect::<Vec<_>>();
+            send_palette(
+                msg,
+                &format!("Allowed colors ({})", swatches.len()),
+                &swatches,
+            )
+        }
+        Some("add") => {
+            let spec = args.collect::<Vec<_>>().join(" ");
+            let (color, name) = parse_color(&data, msg.author.id, &spec)?;
+            let mut allowed = palette::load(&data)?;
+            allowed.retain(|e| e.color != color);
+            let entry = palette::Entry {
+                color,
+                name: name.unwrap_or_default(),
+            };
+            let reply = format!(
+                "Added {} to the palette, which now has {} colors.",
+                entry,
+                allowed.len() + 1
+            );
+            allowed.push(entry);
+            palette::store(&data, &allowed)?;
+            let _ = msg.reply(&reply);
+            Ok(())
+        }
+        Some("remove") => {
+            let spec = args.collect::<Vec<_>>().join(" ");
+            let (color, _) = parse_color(&data, msg.author.id, &spec)?;
+            let mut allowed = palette::load(&data)?;
+            let before = allowed.len();
+            allowed.retain(|e| e.color != color);
+            if allowed.len() == before {
+                bail!("#{} isn't on the palette.", color);
+            }
+            palette::store(&data, &allowed)?;
+            let _ = msg.reply(&format!(
+                "Removed #{} from the palette, which now has {} colors.",
+                color,
+                allowed.len()
+            ));
+            Ok(())
+        }
+        Some("load") => {
+            let presets = names::Namespace::PRESETS
+                .iter()
+                .map(|ns| format!("`{}`", ns.prefix()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let preset = args.next()
+                .ok_or_else(|| format_err!("Choose a preset: {}.", presets))?;
+            let namespace = names::Namespace::from_prefix(&preset)
+                .filter(|ns| names::Namespace::PRESETS.contains(ns))
+                .ok_or_else(|| format_err!("Unknown preset `{}`; choose from {}.", preset, presets))?;
+            // Something like `500` or `muted` picks out part of a big preset.
+            let filter = args.next().map(|f| f.to_lowercase());
+            let mut allowed = palette::load(&data)?;
+            let before = allowed.len();
+            for name in names::all_in(namespace) {
+                let wanted = match filter {
+                    Some(ref f) => name.name.contains(f.as_str()),
+                    None => true,
+                };
+                if wanted && allowed.iter().all(|e| e.color != name.color) {
+                    allowed.push(palette::Entry {
+                        color: name.color,
+                        name: name.to_string(),
+                    });
+                }
+            }
+            if allowed.len() == before {
+                bail!("That didn't add any new colors.");
+            }
+            palette::store(&data, &allowed)?;
+            let _ = msg.reply(&format!(
+                "Added {} colors from `{}` to the palette, which now has {}.",
+                allowed.len() - before,
+                namespace.prefix(),
+                allowed.len()
+            ));
+            Ok(())
+        }
+        Some("clear") => {
+            palette::store(&data, &[])?;
+            let _ = msg.reply("Cleared the palette.");
+            Ok(())
+        }
+        Some(other) => bail!(
+            "Unknown subcommand `{}`; use `allowed`, `add`, `remove`, `load` or `clear`.",
+            other
+        ),
+    }
+}
+
+fn color_suggest(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    const COUNT: usize = 5;
+
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+    let settings = Settings::load(&data)?;
 
+    // A hue in degrees, or any color to take the hue of.
+    let seed = args.collect::<Vec<_>>().join(" ");
+    let hue = if seed.is_empty() {
+        None
+    } else if let Ok(degrees) = seed.trim_end_matches("deg").parse::<f64>() {
+        Some(degrees.rem_euclid(360.0))
+    } else {
+        let (color, _) = parse_color(&data, msg.author.id, &seed)?;
+        let lch = Oklch::from(color);
+        if lch.c < 0.02 {
+            bail!("#{} is gray, so it has no hue to suggest around.", color);
+        }
+        Some(lch.h)
+    };
+
+    let existing = color_roles(&guild.read().roles, &data)?
+        .into_iter()
+        .map(|(c, _, _)| c)
+        .collect::<Vec<_>>();
+    let reservations = reserved::load(&data)?;
+    let allowed = if settings.palette_only == PaletteMode::Off {
+        Vec::new()
+    } else {
+        palette::load(&data)?
+    };
+    if settings.palette_only != PaletteMode::Off && allowed.is_empty() {
+        bail!("There are no colors to pick from.");
+    }
+    let candidates = if allowed.is_empty() {
+        suggest::spread(hue)
+    } else {
+        allowed.iter().map(|e| e.color).collect()
+    };
+    let candidates = candidates
+        .into_iter()
+        .filter(|&c| {
+            (settings.contrast == ContrastPolicy::Off
+                || contrast::failures(c, &settings.themes, settings.min_contrast).is_empty())
+                && reserved::conflict(&reservations, c).is_none()
+        })
+        .collect::<Vec<_>>();
+    let suggestions = suggest::farthest(&existing, &candidates, COUNT);
+    if suggestions.is_empty() {
+        bail!("There's nothing left to suggest.");
+    }
+
+    let swatches = suggestions
+        .iter()
+        .map(|&c| {
+            let caption = existing
+                .iter()
+                .map(|&e| c.delta_e(e))
+                .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                .map_or_else(|| String::from("new"), |d| format!("ΔE {:.0} from others", d));
+            (c, caption)
+        })
+        .collect::<Vec<_>>();
+    let content = format!(
+        "Colors that stand out from this server's {}: {}. Pick one with `%color set`.",
+        if existing.len() == 1 {
+            String::from("1 color")
+        } else {
+            format!("{} colors", existing.len())
+        },
+        suggestions
+            .iter()
+            .map(|c| format!("`#{}`", c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let png = render::strip(&swatches).png();
+    let sent = util::send_image(msg.channel_id, &png, "suggestions.png", |m| {
+        m.content(format!("{}: {}", msg.author.mention(), content))
+            .embed(|e| e.image("attachment://suggestions.png"))
+    });
+    if sent.is_err() {
+        let _ = msg.reply(&content);
+    }
     Ok(())
 }
 
+fn color_harmony(ctx: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let mut args = args.collect::<Vec<_>>();
+    let scheme = match args.last().and_then(|a| harmony::Scheme::from_name(a)) {
+        Some(scheme) => {
+            args.pop();
+            scheme
+        }
+        None => harmony::Scheme::Complementary,
+    };
+    let (color, name) = parse_color(&data, msg.author.id, &args.join(" "))?;
+
+    let companions = harmony::companions(color, scheme);
+    if companions.is_empty() {
+        bail!("#{} is gray, so it has no hue to build a harmony from.", color);
+    }
+    let mut swatches = vec![(color, String::from("base"))];
+    swatches.extend(
+        companions
+            .iter()
+            .map(|&(offset, c)| (c, format!("hue {:+}", offset))),
+    );
+    let content = format!(
+        "{}: The {} harmony for {} is {}. React with a number to take that color.",
+        msg.author.mention(),
+        scheme.name(),
+        describe_color(color, name.as_ref()),
+        companions
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, c))| format!("{} `#{}`", choices::emoji(i), c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let offered = companions.into_iter().map(|(_, c)| c).collect();
+    send_choices(ctx, msg, &content, color, &swatches, offered)
+}
+
+fn color_mix(ctx: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let (space, args) = mix_args(args)?;
+    // An optional last argument says how far to go, like `0.25` or `25%`.
+    let amount = args.last().and_then(|a| match a.strip_suffix('%') {
+        Some(percent) => percent.parse::<f64>().ok().map(|p| p / 100.0),
+        None => a.parse::<f64>().ok(),
+    });
+    let (a, b, t) = match amount {
+        Some(t) if args.len() > 2 => {
+            match parse_two_colors(&data, msg.author.id, &args[..args.len() - 1]) {
+                Ok((a, b)) => (a, b, t),
+                Err(_) => {
+                    let (a, b) = parse_two_colors(&data, msg.author.id, &args)?;
+                    (a, b, 0.5)
+                }
+            }
+        }
+        _ => {
+            let (a, b) = parse_two_colors(&data, msg.author.id, &args)?;
+            (a, b, 0.5)
+        }
+    };
+
+    let mixed = mix::mix(a, b, t, space)?;
+    let swatches = vec![
+        (a, String::from("from")),
+        (mixed, format!("{:.0}% in {}", t * 100.0, space.name())),
+        (b, String::from("to")),
+    ];
+    let content = format!(
+        "{}: #{} and #{} mixed {:.0}% in {} make {} `#{}`. React to take it.",
+        msg.author.mention(),
+        a,
+        b,
+        t * 100.0,
+        space.name(),
+        choices::emoji(0),
+        mixed
+    );
+    send_choices(ctx, msg, &content, mixed, &swatches, vec![mixed])
+}
+
+fn color_gradient(ctx: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let (space, mut args) = mix_args(args)?;
+    let steps = args.pop()
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|n| (2..=choices::MAX).contains(n))
+        .ok_or_else(|| {
+            format_err!(
+                "End with a number of steps from 2 to {}, like `%color gradient red blue 5`.",
+                choices::MAX
+            )
+        })?;
+    let (a, b) = parse_two_colors(&data, msg.author.id, &args)?;
+
+    let steps = mix::gradient(a, b, steps, space)?;
+    let swatches = steps
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| (c, format!("step {}", i + 1)))
+        .collect::<Vec<_>>();
+    let content = format!(
+        "{}: From #{} to #{} in {}: {}. React with a number to take that color.",
+        msg.author.mention(),
+        a,
+        b,
+        space.name(),
+        steps
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!("{} `#{}`", choices::emoji(i), c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    send_choices(ctx, msg, &content, a, &swatches, steps.clone())
+}
+
+/// Takes the `--space=` flag off mix and gradient arguments.
+fn mix_args(args: Args) -> Result<(mix::Space, Vec<String>), Error> {
+    let (flags, args) = args.split_flags();
+    let mut space = mix::Space::Oklab;
+    for flag in &flags {
+        space = match flag.find('=').map(|i| (&flag[..i], &flag[i + 1..])) {
+            Some(("space", value)) => mix::Space::from_name(value)
+                .ok_or_else(|| format_err!("\"{}\" isn't `oklab`, `srgb` or `hsl`.", value))?,
+            _ => bail!("Unknown flag `--{}`.", flag),
+        }
+    }
+    Ok((space, args.into_iter().map(|a| a.into_owned()).collect()))
+}
+
+/// Parses two colors out of arguments, trying each place they could divide,
+/// since either might be a name with spaces.
+fn parse_two_colors(data: &Path, user: UserId, args: &[String]) -> Result<(Color, Color), Error> {
+    for i in 1..args.len() {
+        let a = parse_color(data, user, &args[..i].join(" "));
+        let b = parse_color(data, user, &args[i..].join(" "));
+        if let (Ok((a, _)), Ok((b, _))) = (a, b) {
+            return Ok((a, b));
+        }
+    }
+    bail!("Give two colors, quoting any with spaces in them.");
+}
+
+/// Sends `swatches` with `content` and offers `colors` to pick from.
+fn send_choices(
+    ctx: &Context,
+    msg: &Message,
+    content: &str,
+    colour: Color,
+    swatches: &[(Color, String)],
+    colors: Vec<Color>,
+) -> Result<(), Error> {
+    let png = render::strip(swatches).png();
+    let sent = util::send_image(msg.channel_id, &png, "swatches.png", |m| {
+        m.content(content)
+            .embed(|e| e.colour(colour).image("attachment://swatches.png"))
+    }).or_else(|_| {
+        msg.channel_id
+            .say(content)
+            .map_err(|e| format_err!("Couldn't send colors: {}", e))
+    })?;
+    choices::offer(ctx, &sent, colors);
+    Ok(())
+}
+
+fn color_random(ctx: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    const TRIES: usize = 2000;
+
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+    let settings = Settings::load(&data)?;
+
+    // Arguments are told apart by shape: `200-260` is a hue range, `4.5` or
+    // `4.5:1` a minimum contrast, and `palette` or a preset name a set of
+    // colors to pick from.
+    let mut hues = None;
+    let mut min_contrast = None;
+    let mut from = None;
+    for arg in args {
+        let arg = arg.to_lowercase();
+        // Skipping the first character lets the range start negative.
+        let range = arg.char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| (&arg[..i], &arg[i + 1..]));
+        if let Some((Ok(lo), Ok(hi))) = range.map(|(lo, hi)| (lo.parse::<f64>(), hi.parse::<f64>())) {
+            if !lo.is_finite() || !hi.is_finite() {
+                bail!("{} isn't a hue range; give degrees like `200-260`.", arg);
+            }
+            hues = Some((lo.rem_euclid(360.0), hi.rem_euclid(360.0)));
+        } else if let Ok(ratio) = arg.trim_end_matches(":1").parse::<f64>() {
+            if !(1.0..=21.0).contains(&ratio) {
+                bail!("{} isn't a contrast ratio between 1 and 21.", arg);
+            }
+            min_contrast = Some(ratio);
+        } else if arg == "palette" {
+            from = Some(palette::load(&data)?.into_iter().map(|e| e.color).collect::<Vec<_>>());
+        } else if let Some(ns) = names::Namespace::from_prefix(&arg) {
+            from = Some(names::all_in(ns).into_iter().map(|n| n.color).collect());
+        } else {
+            bail!(
+                "\"{}\" isn't a hue range like `200-260`, a contrast like `4.5:1`, `palette` or a preset.",
+                arg
+            );
+        }
+    }
+    // The admin palette applies whether or not it was asked for, narrowing a
+    // preset down to the colors it shares with the palette.
+    if settings.palette_only != PaletteMode::Off {
+        let allowed = palette::load(&data)?.into_iter().map(|e| e.color).collect::<Vec<_>>();
+        from = Some(match from {
+            Some(from) => from.into_iter().filter(|c| allowed.contains(c)).collect(),
+            None => allowed,
+        });
+    }
+    if let Some(ref from) = from {
+        if from.is_empty() {
+            bail!("There are no colors to pick from.");
+        }
+    }
+
+    let reservations = reserved::load(&data)?;
+    let taken = if settings.unique > 0.0 {
+        member_colors(&data)?
+            .into_iter()
+            .filter(|&(user, _)| user != msg.author.id)
+            .map(|(_, c)| c)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let min = match (settings.contrast, min_contrast) {
+        (ContrastPolicy::Off, Some(min)) => Some(min),
+        (_, Some(min)) => Some(min.max(settings.min_contrast)),
+        (ContrastPolicy::Off, None) => None,
+        (_, None) => Some(settings.min_contrast),
+    };
+    // Hue ranges can wrap around, like `330-30` for reds.
+    let in_range = |c: Color| {
+        let lch = Oklch::from(c);
+        match hues {
+            Some((lo, hi)) if lo < hi => lch.c >= 0.02 && lo <= lch.h && lch.h <= hi,
+            Some((lo, hi)) if lo > hi => lch.c >= 0.02 && (lch.h >= lo || lch.h <= hi),
+            _ => true,
+        }
+    };
+    let acceptable = |c: Color| {
+        in_range(c)
+            && match min {
+                Some(min) => contrast::failures(c, &settings.themes, min).is_empty(),
+                None => true,
+            }
+            && reserved::conflict(&reservations, c).is_none()
+            && taken.iter().all(|&t| c.delta_e(t) >= settings.unique)
+    };
+
+    let mut rng = rand::thread_rng();
+    let color = match from {
+        Some(colors) => {
+            let colors = colors.into_iter().filter(|&c| acceptable(c)).collect::<Vec<_>>();
+            rng.choose(&colors).cloned()
+        }
+        None => (0..TRIES)
+            .map(|_| {
+                let (lo, hi) = hues.unwrap_or((0.0, 0.0));
+                let span = (hi - lo).rem_euclid(360.0);
+                let span = if span == 0.0 { 360.0 } else { span };
+                Color::from(Oklch {
+                    l: rng.gen_range(0.35, 0.85),
+                    c: rng.gen_range(0.05, 0.2),
+                    h: (lo + rng.gen_range(0.0, span)).rem_euclid(360.0),
+                })
+            })
+            .find(|&c| acceptable(c)),
+    }.ok_or_else(|| format_err!("Couldn't find a color that fits all of that."))?;
+
+    let content = format!(
+        "How about `#{}`? React with {} to take it.",
+        color,
+        choices::emoji(0)
+    );
+    if let Some(sent) = send_preview(msg.channel_id, &msg.author, &content, color, "") {
+        choices::offer(ctx, &sent, vec![color]);
+    }
+    Ok(())
+}
+
+fn color_from_image(ctx: &mut Context, msg: &Message, _: Args) -> Result<(), Error> {
+    const COLORS: usize = 5;
+
+    // An attached image wins; otherwise it's the avatar of whoever's
+    // mentioned, or the author's own.
+    let (url, source) = match msg.attachments.first() {
+        Some(attachment) => (png_rendition(attachment)?, String::from("that image")),
+        None => {
+            let user = msg.mentions.first().unwrap_or(&msg.author);
+            // The CDN converts avatars to PNG on request, animated or not.
+            let url = match user.avatar {
+                Some(ref hash) => format!(
+                    "https://cdn.discordapp.com/avatars/{}/{}.png?size=128",
+                    user.id, hash
+                ),
+                None => user.default_avatar_url(),
+            };
+            let source = if user.id == msg.author.id {
+                String::from("your avatar")
+            } else {
+                format!("{}'s avatar", user.name)
+            };
+            (url, source)
+        }
+    };
+
+    let colors = dominant::from_url(&fetch::HttpFetch::new()?, &url, COLORS)?;
+    let swatches = colors
+        .iter()
+        .map(|&(c, share)| (c, format!("{:.0}%", share * 100.0)))
+        .collect::<Vec<_>>();
+    let content = format!(
+        "{}: The main colors in {} are {}. React with a number to take that color.",
+        msg.author.mention(),
+        source,
+        colors
+            .iter()
+            .enumerate()
+            .map(|(i, &(c, _))| format!("{} `#{}`", choices::emoji(i), c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let offered = colors.iter().map(|&(c, _)| c).collect();
+    send_choices(ctx, msg, &content, colors[0].0, &swatches, offered)
+}
+
+/// A URL for `attachment` as a PNG no bigger than 512×512. Discord's media
+/// proxy converts JPEG, GIF and WebP uploads on request, like the CDN does
+/// for avatars, so only PNG ever has to be decoded.
+fn png_rendition(attachment: &Attachment) -> Result<String, Error> {
+    const SIZE: u64 = 512;
+
+    let (width, height) = attachment
+        .dimensions()
+        .ok_or_else(|| format_err!("That attachment isn't an image."))?;
+    let scale = |n: u64| (n * SIZE / width.max(height)).max(1);
+    let (width, height) = if width.max(height) > SIZE {
+        (scale(width), scale(height))
+    } else {
+        (width, height)
+    };
+    // Proxy URLs can already have a query, like the signature on newer ones.
+    let separator = if attachment.proxy_url.contains('?') { '&' } else { '?' };
+    Ok(format!(
+        "{}{}format=png&width={}&height={}",
+        attachment.proxy_url, separator, width, height
+    ))
+}
+
+fn send_palette(msg: &Message, title: &str, swatches: &[(Color, usize)]) -> Result<(), Error> {
+    let png = render::palette(swatches).png();
+    util::send_image(msg.channel_id, &png, "palette.png", |m| {
+        m.embed(|e| e.title(title).image("attachment://palette.png"))
+    })?;
+    Ok(())
+}
+
+/// The guild's color roles that still exist, with their colors and how many
+/// members use them.
+fn color_roles(
+    roles: &HashMap<RoleId, Role>,
+    data: &Path,
+) -> Result<Vec<(Color, RoleId, usize)>, Error> {
+    let mut members: HashMap<RoleId, usize> = HashMap::new();
+    if let Some(mut users) = db::Guild::Users.open(data)? {
+        for (_, v) in users.iter() {
+            if let Some(role) = str::from_utf8(v).ok().and_then(|s| s.parse::<RoleId>().ok()) {
+                *members.entry(role).or_insert(0) += 1;
+            }
+        }
+    }
+    let mut entries = Vec::new();
+    if let Some(mut colors) = db::Guild::Colors.open(data)? {
+        for (k, v) in colors.iter() {
+            let color = str::from_utf8(k).ok().and_then(|s| s.parse::<Color>().ok());
+            let role = str::from_utf8(v).ok().and_then(|s| s.parse::<RoleId>().ok());
+            if let (Some(color), Some(role)) = (color, role) {
+                if roles.contains_key(&role) {
+                    entries.push((color, role, members.get(&role).cloned().unwrap_or(0)));
+                }
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Every member with a color, and that color.
+fn member_colors(data: &Path) -> Result<Vec<(UserId, Color)>, Error> {
+    let mut role_colors = HashMap::new();
+    if let Some(mut colors) = db::Guild::Colors.open(data)? {
+        for (k, v) in colors.iter() {
+            let color = str::from_utf8(k).ok().and_then(|s| s.parse::<Color>().ok());
+            let role = str::from_utf8(v).ok().and_then(|s| s.parse::<RoleId>().ok());
+            if let (Some(color), Some(role)) = (color, role) {
+                role_colors.insert(role, color);
+            }
+        }
+    }
+    let mut members = Vec::new();
+    if let Some(mut users) = db::Guild::Users.open(data)? {
+        for (k, v) in users.iter() {
+            let user = str::from_utf8(k).ok().and_then(|s| s.parse::<UserId>().ok());
+            let role = str::from_utf8(v).ok().and_then(|s| s.parse::<RoleId>().ok());
+            if let (Some(user), Some(&color)) = (user, role.and_then(|r| role_colors.get(&r))) {
+                members.push((user, color));
+            }
+        }
+    }
+    Ok(members)
+}
+
+/// Sorts grays first, darkest to lightest, then around the OKLCh hue wheel.
+fn sort_by_hue<T>(entries: &mut [(Color, T, usize)]) {
+    let key = |c: Color| {
+        let lch = Oklch::from(c);
+        let hue = if lch.c < 0.02 { -1.0 } else { lch.h };
+        (hue, lch.l)
+    };
+    entries.sort_by(|a, b| key(a.0).partial_cmp(&key(b.0)).unwrap_or(Ordering::Equal));
+}
+
+/// Parses a color given as command arguments, along with its name if it was
+/// given by one. `@name` is one of `user`'s favorites, and the guild's own
+/// names in `data` come before the built-in ones.
+fn parse_color(data: &Path, user: UserId, spec: &str) -> Result<(Color, Option<String>), Error> {
+    parse_color_anywhere(Some(data), user, spec)
+}
+
+/// `parse_color` for commands that also run outside guilds, which have no
+/// `data` and so no names of their own.
+fn parse_color_anywhere(
+    data: Option<&Path>,
+    user: UserId,
+    spec: &str,
+) -> Result<(Color, Option<String>), Error> {
+    // Names like `xkcd:dusty rose` contain spaces, so callers pass all the
+    // remaining arguments.
+    if spec.is_empty() {
+        bail!("You must provide a color.");
+    }
+    if spec.starts_with('@') {
+        let favorites = favorites::load(user)?;
+        let favorite = favorites::lookup(&favorites, spec).ok_or_else(|| {
+            format_err!(
+                "You haven't saved a color called {}; see `%color favorites`.",
+                spec
+            )
+        })?;
+        return Ok((favorite.color, Some(format!("@{}", favorite.name))));
+    }
+    if let Some(data) = data {
+        if let Some(d) = defined::lookup(&defined::load(data)?, spec) {
+            return Ok((d.color, Some(d.name.clone())));
+        }
+    }
+    let color = spec.parse::<Color>()
+        .map_err(|e| format_err!("Color parsing: {}", e))?;
+    Ok((color, names::lookup(spec).map(|n| n.to_string())))
+}
+
+fn describe_color(color: Color, name: Option<&String>) -> String {
+    match name {
+        Some(name) => format!("{} (#{})", name, color),
+        None => format!("#{}", color),
+    }
+}
+
+/// Replies to `user` with `content` and a rendered preview of `color`, or
+/// with just the text if the image can't be sent (say, without Attach Files).
+fn send_preview(
+    channel: ChannelId,
+    user: &User,
+    content: &str,
+    color: Color,
+    label: &str,
+) -> Option<Message> {
+    let content = format!("{}: {}", user.mention(), content);
+    let png = render::preview(color, label, &user.name).png();
+    util::send_image(channel, &png, "preview.png", |m| {
+        m.content(&content)
+            .embed(|e| e.colour(color).image("attachment://preview.png"))
+    }).ok()
+        .or_else(|| channel.say(&content).ok())
+}
+
+/// Finds the guild's existing color role closest to `color`, returning its
+/// color and CIEDE2000 distance.
+fn nearest_color_role(
+    colors: &mut Cdb,
+    roles: &HashMap<RoleId, Role>,
+    color: Color,
+) -> Option<(Color, f64)> {
+    colors
+        .iter()
+        .filter_map(|(k, v)| {
+            let existing = str::from_utf8(k).ok()?.parse::<Color>().ok()?;
+            let role = str::from_utf8(v).ok()?.parse::<RoleId>().ok()?;
+            if roles.contains_key(&role) {
+                Some((existing, color.delta_e(existing)))
+            } else {
+                None
+            }
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+}
+
+/// Describes the staff roles `color` could be mistaken for, like "@Mods (for
+/// everyone)", or "@Mods (with deuteranopia)" under a simulated color vision
+/// deficiency. Staff roles are colored roles that can moderate.
+fn staff_lookalikes(roles: &HashMap<RoleId, Role>, color: Color, distance: f64) -> Vec<String> {
+    let staff = Permissions::ADMINISTRATOR
+        | Permissions::MANAGE_GUILD
+        | Permissions::MANAGE_ROLES
+        | Permissions::MANAGE_MESSAGES
+        | Permissions::KICK_MEMBERS
+        | Permissions::BAN_MEMBERS;
+    let mut roles = roles
+        .values()
+        .filter(|r| r.colour.0 != 0 && r.permissions.intersects(staff))
+        .collect::<Vec<_>>();
+    roles.sort_by_key(|r| -r.position);
+    roles
+        .into_iter()
+        .filter_map(|r| {
+            let who = match cvd::confusion(color, Color::from(r.colour), distance)? {
+                Confusion::Everyone => String::from("for everyone"),
+                Confusion::With(deficiencies) => format!(
+                    "with {}",
+                    deficiencies
+                        .iter()
+                        .map(|d| d.name())
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+            };
+            Some(format!("@{} ({})", r.name, who))
+        })
+        .collect()
+}
+
 fn color_unset(_: &mut Context, msg: &Message, _: Args) -> Result<(), Error> {
     let guild = msg.guild()
         .ok_or_else(|| format_err!("This command should only run in guilds."))?;
@@ -518,6 +1852,258 @@ fn color_clean(_: &mut Context, msg: &Message, _: Args) -> Result<(), Error> {
     Ok(())
 }
 
+fn color_reserve(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let (flags, args) = args.split_flags();
+    let mut radius = reserved::DEFAULT_RADIUS;
+    for flag in &flags {
+        match flag.find('=').map(|i| (&flag[..i], &flag[i + 1..])) {
+            Some(("radius", value)) => {
+                radius = match value.parse::<f64>() {
+                    Ok(r) if r > 0.0 && r <= 100.0 => r,
+                    _ => bail!("\"{}\" isn't a ΔE between 0 and 100.", value),
+                }
+            }
+            _ => bail!("Unknown flag `--{}`.", flag),
+        }
+    }
+    let (color, name) = parse_color(&data, msg.author.id, &args.join(" "))?;
+
+    let mut reservations = reserved::load(&data)?;
+    reservations.retain(|r| r.color != color);
+    reservations.push(reserved::Reservation { color, radius });
+    reserved::store(&data, &reservations)?;
+    let _ = msg.reply(&format!(
+        "Reserved {}; members can't pick colors within ΔE {} of it.",
+        describe_color(color, name.as_ref()),
+        radius
+    ));
+    Ok(())
+}
+
+fn color_unreserve(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let (color, _) = parse_color(&data, msg.author.id, &args.collect::<Vec<_>>().join(" "))?;
+    let mut reservations = reserved::load(&data)?;
+    let before = reservations.len();
+    reservations.retain(|r| r.color != color);
+    if reservations.len() == before {
+        bail!("#{} isn't reserved.", color);
+    }
+    reserved::store(&data, &reservations)?;
+    let _ = msg.reply(&format!("#{} is no longer reserved.", color));
+    Ok(())
+}
+
+fn color_reserved(_: &mut Context, msg: &Message, _: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let reservations = reserved::load(&data)?;
+    if reservations.is_empty() {
+        let _ = msg.reply("No colors are reserved.");
+        return Ok(());
+    }
+    let mut reply = String::from("Reserved colors:");
+    for r in &reservations {
+        reply.push_str(&format!("\n`#{}` within ΔE {}", r.color, r.radius));
+    }
+    let _ = msg.reply(&reply);
+    Ok(())
+}
+
+fn color_define(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    // The name comes first, quoted if it has spaces, so the color can be
+    // anything `%color set` takes.
+    let name = args.next()
+        .ok_or_else(|| format_err!("Give a name and a color, like `%color define brand 5865f2`."))?;
+    let name = defined::validate(&name)?;
+    let (color, _) = parse_color(&data, msg.author.id, &args.collect::<Vec<_>>().join(" "))?;
+
+    let mut definitions = defined::load(&data)?;
+    let replaced = definitions.iter().position(|d| d.name == name).map(|i| definitions.remove(i));
+    definitions.push(defined::Definition {
+        name: name.clone(),
+        color,
+    });
+    defined::store(&data, &definitions)?;
+    let _ = msg.reply(&match replaced {
+        Some(old) => format!("\"{}\" is now #{} instead of #{}.", name, color, old.color),
+        None => format!("\"{}\" now means #{} on this server.", name, color),
+    });
+    Ok(())
+}
+
+fn color_undefine(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let name = args.collect::<Vec<_>>().join(" ");
+    let mut definitions = defined::load(&data)?;
+    let definition = defined::lookup(&definitions, &name)
+        .cloned()
+        .ok_or_else(|| format_err!("\"{}\" isn't defined on this server.", name))?;
+    definitions.retain(|d| d.name != definition.name);
+    defined::store(&data, &definitions)?;
+    let _ = msg.reply(&format!(
+        "\"{}\" no longer means #{}.",
+        definition.name, definition.color
+    ));
+    Ok(())
+}
+
+fn color_defined(_: &mut Context, msg: &Message, _: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let definitions = defined::load(&data)?;
+    if definitions.is_empty() {
+        let _ = msg.reply("No color names are defined; admins can add some with `%color define`.");
+        return Ok(());
+    }
+    let mut reply = String::from("This server's color names:");
+    for d in &definitions {
+        reply.push_str(&format!("\n`{}` is `#{}`", d.name, d.color));
+    }
+    let _ = msg.reply(&reply);
+    Ok(())
+}
+
+fn color_save(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
+    // Favorites work in DMs too, where there's no guild to take a color or
+    // names from.
+    let data = msg.guild().map(|guild| {
+        let guild_id = { guild.read().id };
+        db::data(&format!("{}", guild_id))
+    });
+
+    let name = args.next().ok_or_else(|| {
+        format_err!("Give a name to save under, like `%color save fav1` for your current color.")
+    })?;
+    let name = favorites::validate(&name)?;
+    // Without a color, it's the one they're wearing here.
+    let spec = args.collect::<Vec<_>>().join(" ");
+    let color = match data {
+        Some(ref data) if spec.is_empty() => member_colors(data)?
+            .into_iter()
+            .find(|&(user, _)| user == msg.author.id)
+            .map(|(_, c)| c)
+            .ok_or_else(|| format_err!("You have no color here to save; give one to save."))?,
+        None if spec.is_empty() => bail!("Give a color to save, like `%color save fav1 #ff8800`."),
+        _ => parse_color_anywhere(data.as_deref(), msg.author.id, &spec)?.0,
+    };
+
+    let replaced = favorites::update(msg.author.id, |saved| {
+        favorites::add(
+            saved,
+            favorites::Favorite {
+                name: name.clone(),
+                color,
+            },
+        )
+    })?;
+    let mut reply = format!(
+        "Saved #{} as `@{}`; use `%color set @{}` in any server to wear it.",
+        color, name, name
+    );
+    if let Some(old) = replaced {
+        reply.push_str(&format!(" (It was #{}.)", old.color));
+    }
+    let _ = msg.reply(&reply);
+    Ok(())
+}
+
+fn color_forget(_: &mut Context, msg: &Message, args: Args) -> Result<(), Error> {
+    let name = args.collect::<Vec<_>>().join(" ");
+    let favorite = favorites::update(msg.author.id, |saved| {
+        let favorite = favorites::lookup(saved, &name)
+            .cloned()
+            .ok_or_else(|| {
+                format_err!(
+                    "You haven't saved a color called `@{}`.",
+                    name.trim_start_matches('@')
+                )
+            })?;
+        saved.retain(|f| f.name != favorite.name);
+        Ok(favorite)
+    })?;
+    let _ = msg.reply(&format!("Forgot `@{}` (#{}).", favorite.name, favorite.color));
+    Ok(())
+}
+
+fn color_favorites(_: &mut Context, msg: &Message, _: Args) -> Result<(), Error> {
+    let saved = favorites::load(msg.author.id)?;
+    if saved.is_empty() {
+        let _ = msg.reply("You haven't saved any colors; save one with `%color save <name>`.");
+        return Ok(());
+    }
+    let mut reply = String::from("Your saved colors, for `%color set @<name>` in any server:");
+    for f in &saved {
+        reply.push_str(&format!("\n`@{}` is `#{}`", f.name, f.color));
+    }
+    let _ = msg.reply(&reply);
+    Ok(())
+}
+
+fn color_config(_: &mut Context, msg: &Message, mut args: Args) -> Result<(), Error> {
+    let guild = msg.guild()
+        .ok_or_else(|| format_err!("This command should only run in guilds."))?;
+    let guild_id = { guild.read().id };
+    let guild_str = format!("{}", guild_id);
+    let data = db::data(&guild_str);
+
+    let key = match args.next() {
+        Some(key) => key,
+        None => {
+            let settings = Settings::load(&data)?;
+            let mut reply = String::from("Settings (`%color config <setting> <value|reset>`):");
+            for &(key, description) in settings::KEYS {
+                reply.push_str(&format!(
+                    "\n`{}` = `{}`: {}",
+                    key,
+                    settings.get(key)?,
+                    description
+                ));
+            }
+            let _ = msg.reply(&reply);
+            return Ok(());
+        }
+    };
+    let value = args.collect::<Vec<_>>().join(" ");
+    let settings = match value.as_ref() {
+        "" => Settings::load(&data)?,
+        "reset" => Settings::store(&data, &key, None)?,
+        value => Settings::store(&data, &key, Some(value))?,
+    };
+    let _ = msg.reply(&format!("`{}` = `{}`", key, settings.get(&key)?));
+    Ok(())
+}
+
 fn main() {
     dotenv().ok();
 
@@ -529,3 +2115,43 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(proxy_url: &str, dimensions: Option<(u64, u64)>) -> Attachment {
+        Attachment {
+            id: String::from("1"),
+            filename: String::from("cat.jpg"),
+            height: dimensions.map(|d| d.1),
+            proxy_url: proxy_url.to_string(),
+            size: 1024,
+            url: proxy_url.replace("media.discordapp.net", "cdn.discordapp.com"),
+            width: dimensions.map(|d| d.0),
+        }
+    }
+
+    #[test]
+    fn png_renditions() {
+        let url = "https://media.discordapp.net/attachments/1/2/cat.jpg";
+        assert_eq!(
+            png_rendition(&attachment(url, Some((300, 200)))).unwrap(),
+            format!("{}?format=png&width=300&height=200", url)
+        );
+        assert_eq!(
+            png_rendition(&attachment(url, Some((2048, 1024)))).unwrap(),
+            format!("{}?format=png&width=512&height=256", url)
+        );
+        assert!(png_rendition(&attachment(url, None)).is_err());
+    }
+
+    #[test]
+    fn png_renditions_keep_signed_queries() {
+        let url = "https://media.discordapp.net/attachments/1/2/cat.jpg?ex=1&is=2&hm=3";
+        assert_eq!(
+            png_rendition(&attachment(url, Some((10, 10)))).unwrap(),
+            format!("{}&format=png&width=10&height=10", url)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// Interpolating between two colors. OKLab gives even perceptual steps; sRGB
+// and HSL are there to match what other tools produce.
+
+use failure::Error;
+
+use color::{Color, Hsl, Oklab, Rgb};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Space {
+    Oklab,
+    Srgb,
+    Hsl,
+}
+impl Space {
+    pub const ALL: [Space; 3] = [Space::Oklab, Space::Srgb, Space::Hsl];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Space::Oklab => "oklab",
+            Space::Srgb => "srgb",
+            Space::Hsl => "hsl",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Space> {
+        let name = name.trim().to_lowercase();
+        Space::ALL.iter().cloned().find(|s| s.name() == name)
+    }
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// The color `t` of the way from `a` to `b`, where 0 is `a` and 1 is `b`.
+pub fn mix(a: Color, b: Color, t: f64, space: Space) -> Result<Color, Error> {
+    if !(0.0..=1.0).contains(&t) {
+        bail!("Mix amounts go from 0 to 1, or 0% to 100%.");
+    }
+    Ok(at(a, b, t, space))
+}
+
+fn at(a: Color, b: Color, t: f64, space: Space) -> Color {
+    match space {
+        Space::Oklab => {
+            let (a, b) = (Oklab::from(a), Oklab::from(b));
+            Color::from(Oklab {
+                l: lerp(a.l, b.l, t),
+                a: lerp(a.a, b.a, t),
+                b: lerp(a.b, b.b, t),
+            })
+        }
+        Space::Srgb => {
+            let (a, b) = (Rgb::from(a), Rgb::from(b));
+            Color::from(Rgb {
+                r: lerp(a.r, b.r, t),
+                g: lerp(a.g, b.g, t),
+                b: lerp(a.b, b.b, t),
+            })
+        }
+        Space::Hsl => {
+            let (mut a, mut b) = (Hsl::from(a), Hsl::from(b));
+            // Grays have no real hue, so they take the other color's.
+            if a.s == 0.0 {
+                a.h = b.h;
+            }
+            if b.s == 0.0 {
+                b.h = a.h;
+            }
+            // Go the short way around the hue wheel.
+            let mut dh = b.h - a.h;
+            if dh > 180.0 {
+                dh -= 360.0;
+            } else if dh < -180.0 {
+                dh += 360.0;
+            }
+            Color::from(Hsl {
+                h: (a.h + dh * t).rem_euclid(360.0),
+                s: lerp(a.s, b.s, t),
+                l: lerp(a.l, b.l, t),
+            })
+        }
+    }
+}
+
+/// `steps` evenly spaced colors from `a` to `b`, including both.
+pub fn gradient(a: Color, b: Color, steps: usize, space: Space) -> Result<Vec<Color>, Error> {
+    if steps < 2 {
+        bail!("A gradient needs at least 2 steps, one for each end.");
+    }
+    Ok((0..steps)
+        .map(|i| at(a, b, i as f64 / (steps - 1) as f64, space))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color(0xff, 0, 0);
+    const BLUE: Color = Color(0, 0, 0xff);
+
+    #[test]
+    fn ends_are_the_inputs() {
+        for &space in &Space::ALL {
+            assert_eq!(mix(RED, BLUE, 0.0, space).unwrap(), RED, "{}", space.name());
+            assert_eq!(mix(RED, BLUE, 1.0, space).unwrap(), BLUE, "{}", space.name());
+        }
+    }
+
+    #[test]
+    fn midpoints() {
+        // OKLab's midpoint of red and blue, from its published matrices.
+        assert_eq!(mix(RED, BLUE, 0.5, Space::Oklab).unwrap(), Color(0x8c, 0x53, 0xa2));
+        assert_eq!(mix(RED, BLUE, 0.5, Space::Srgb).unwrap(), Color(0x80, 0, 0x80));
+        // Red to blue the short way round goes through magenta.
+        assert_eq!(mix(RED, BLUE, 0.5, Space::Hsl).unwrap(), Color(0xff, 0, 0xff));
+    }
+
+    #[test]
+    fn amounts_outside_0_to_1_are_errors() {
+        for &t in &[-0.1, 1.1, f64::NAN, f64::INFINITY] {
+            assert!(mix(RED, BLUE, t, Space::Oklab).is_err(), "{}", t);
+        }
+    }
+
+    #[test]
+    fn gradient_steps() {
+        for steps in 2..10 {
+            let colors = gradient(RED, BLUE, steps, Space::Oklab).unwrap();
+            assert_eq!(colors.len(), steps);
+            assert_eq!(colors[0], RED);
+            assert_eq!(colors[steps - 1], BLUE);
+        }
+        let three = gradient(RED, BLUE, 3, Space::Oklab).unwrap();
+        assert_eq!(three[1], mix(RED, BLUE, 0.5, Space::Oklab).unwrap());
+    }
+
+    #[test]
+    fn gradients_need_two_steps() {
+        assert!(gradient(RED, BLUE, 0, Space::Oklab).is_err());
+        assert!(gradient(RED, BLUE, 1, Space::Oklab).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+// The Material Design (2014) color palette, shades 50 through 900.
+
+pub static NAMES: &[(&str, u32)] = &[
+    ("red-50", 0xffebee),
+    ("red-100", 0xffcdd2),
+    ("red-200", 0xef9a9a),
+    ("red-300", 0xe57373),
+    ("red-400", 0xef5350),
+    ("red-500", 0xf44336),
+    ("red-600", 0xe53935),
+    ("red-700", 0xd32f2f),
+    ("red-800", 0xc62828),
+    ("red-900", 0xb71c1c),
+    ("pink-50", 0xfce4ec),
+    ("pink-100", 0xf8bbd0),
+    ("pink-200", 0xf48fb1),
+    ("pink-300", 0xf06292),
+    ("pink-400", 0xec407a),
+    ("pink-500", 0xe91e63),
+    ("pink-600", 0xd81b60),
+    ("pink-700", 0xc2185b),
+    ("pink-800", 0xad1457),
+    ("pink-900", 0x880e4f),
+    ("purple-50", 0xf3e5f5),
+    ("purple-100", 0xe1bee7),
+    ("purple-200", 0xce93d8),
+    ("purple-300", 0xba68c8),
+    ("purple-400", 0xab47bc),
+    ("purple-500", 0x9c27b0),
+    ("purple-600", 0x8e24aa),
+    ("purple-700", 0x7b1fa2),
+    ("purple-800", 0x6a1b9a),
+    ("purple-900", 0x4a148c),
+    ("deep-purple-50", 0xede7f6),
+    ("deep-purple-100", 0xd1c4e9),
+    ("deep-purple-200", 0xb39ddb),
+    ("deep-purple-300", 0x9575cd),
+    ("deep-purple-400", 0x7e57c2),
+    ("deep-purple-500", 0x673ab7),
+    ("deep-purple-600", 0x5e35b1),
+    ("deep-purple-700", 0x512da8),
+    ("deep-purple-800", 0x4527a0),
+    ("deep-purple-900", 0x311b92),
+    ("indigo-50", 0xe8eaf6),
+    ("indigo-100", 0xc5cae9),
+    ("indigo-200", 0x9fa8da),
+    ("indigo-300", 0x7986cb),
+    ("indigo-400", 0x5c6bc0),
+    ("indigo-500", 0x3f51b5),
+    ("indigo-600", 0x3949ab),
+    ("indigo-700", 0x303f9f),
+    ("indigo-800", 0x283593),
+    ("indigo-900", 0x1a237e),
+    ("blue-50", 0xe3f2fd),
+    ("blue-100", 0xbbdefb),
+    ("blue-200", 0x90caf9),
+    ("blue-300", 0x64b5f6),
+    ("blue-400", 0x42a5f5),
+    ("blue-500", 0x2196f3),
+    ("blue-600", 0x1e88e5),
+    ("blue-700", 0x1976d2),
+    ("blue-800", 0x1565c0),
+    ("blue-900", 0x0d47a1),
+    ("light-blue-50", 0xe1f5fe),
+    ("light-blue-100", 0xb3e5fc),
+    ("light-blue-200", 0x81d4fa),
+    ("light-blue-300", 0x4fc3f7),
+    ("light-blue-400", 0x29b6f6),
+    ("light-blue-500", 0x03a9f4),
+    ("light-blue-600", 0x039be5),
+    ("light-blue-700", 0x0288d1),
+    ("light-blue-800", 0x0277bd),
+    ("light-blue-900", 0x01579b),
+    ("cyan-50", 0xe0f7fa),
+    ("cyan-100", 0xb2ebf2),
+    ("cyan-200", 0x80deea),
+    ("cyan-300", 0x4dd0e1),
+    ("cyan-400", 0x26c6da),
+    ("cyan-500", 0x00bcd4),
+    ("cyan-600", 0x00acc1),
+    ("cyan-700", 0x0097a7),
+    ("cyan-800", 0x00838f),
+    ("cyan-900", 0x006064),
+    ("teal-50", 0xe0f2f1),
+    ("teal-100", 0xb2dfdb),
+    ("teal-200", 0x80cbc4),
+    ("teal-300", 0x4db6ac),
+    ("teal-400", 0x26a69a),
+    ("teal-500", 0x009688),
+    ("teal-600", 0x00897b),
+    ("teal-700", 0x00796b),
+    ("teal-800", 0x00695c),
+    ("teal-900", 0x004d40),
+    ("green-50", 0xe8f5e9),
+    ("green-100", 0xc8e6c9),
+    ("green-200", 0xa5d6a7),
+    ("green-300", 0x81c784),
+    ("green-400", 0x66bb6a),
+    ("green-500", 0x4caf50),
+    ("green-600", 0x43a047),
+    ("green-700", 0x388e3c),
+    ("green-800", 0x2e7d32),
+    ("green-900", 0x1b5e20),
+    ("light-green-50", 0xf1f8e9),
+    ("light-green-100", 0xdcedc8),
+    ("light-green-200", 0xc5e1a5),
+    ("light-green-300", 0xaed581),
+    ("light-green-400", 0x9ccc65),
+    ("light-green-500", 0x8bc34a),
+    ("light-green-600", 0x7cb342),
+    ("light-green-700", 0x689f38),
+    ("light-green-800", 0x558b2f),
+    ("light-green-900", 0x33691e),
+    ("lime-50", 0xf9fbe7),
+    ("lime-100", 0xf0f4c3),
+    ("lime-200", 0xe6ee9c),
+    ("lime-300", 0xdce775),
+    ("lime-400", 0xd4e157),
+    ("lime-500", 0xcddc39),
+    ("lime-600", 0xc0ca33),
+    ("lime-700", 0xafb42b),
+    ("lime-800", 0x9e9d24),
+    ("lime-900", 0x827717),
+    ("yellow-50", 0xfffde7),
+    ("yellow-100", 0xfff9c4),
+    ("yellow-200", 0xfff59d),
+    ("yellow-300", 0xfff176),
+    ("yellow-400", 0xffee58),
+    ("yellow-500", 0xffeb3b),
+    ("yellow-600", 0xfdd835),
+    ("yellow-700", 0xfbc02d),
+    ("yellow-800", 0xf9a825),
+    ("yellow-900", 0xf57f17),
+    ("amber-50", 0xfff8e1),
+    ("amber-100", 0xffecb3),
+    ("amber-200", 0xffe082),
+    ("amber-300", 0xffd54f),
+    ("amber-400", 0xffca28),
+    ("amber-500", 0xffc107),
+    ("amber-600", 0xffb300),
+    ("amber-700", 0xffa000),
+    ("amber-800", 0xff8f00),
+    ("amber-900", 0xff6f00),
+    ("orange-50", 0xfff3e0),
+    ("orange-100", 0xffe0b2),
+    ("orange-200", 0xffcc80),
+    ("orange-300", 0xffb74d),
+    ("orange-400", 0xffa726),
+    ("orange-500", 0xff9800),
+    ("orange-600", 0xfb8c00),
+    ("orange-700", 0xf57c00),
+    ("orange-800", 0xef6c00),
+    ("orange-900", 0xe65100),
+    ("deep-orange-50", 0xfbe9e7),
+    ("deep-orange-100", 0xffccbc),
+    ("deep-orange-200", 0xffab91),
+    ("deep-orange-300", 0xff8a65),
+    ("deep-orange-400", 0xff7043),
+    ("deep-orange-500", 0xff5722),
+    ("deep-orange-600", 0xf4511e),
+    ("deep-orange-700", 0xe64a19),
+    ("deep-orange-800", 0xd84315),
+    ("deep-orange-900", 0xbf360c),
+    ("brown-50", 0xefebe9),
+    ("brown-100", 0xd7ccc8),
+    ("brown-200", 0xbcaaa4),
+    ("brown-300", 0xa1887f),
+    ("brown-400", 0x8d6e63),
+    ("brown-500", 0x795548),
+    ("brown-600", 0x6d4c41),
+    ("brown-700", 0x5d4037),
+    ("brown-800", 0x4e342e),
+    ("brown-900", 0x3e2723),
+    ("grey-50", 0xfafafa),
+    ("grey-100", 0xf5f5f5),
+    ("grey-200", 0xeeeeee),
+    ("grey-300", 0xe0e0e0),
+    ("grey-400", 0xbdbdbd),
+    ("grey-500", 0x9e9e9e),
+    ("grey-600", 0x757575),
+    ("grey-700", 0x616161),
+    ("grey-800", 0x424242),
+    ("grey-900", 0x212121),
+    ("blue-grey-50", 0xeceff1),
+    ("blue-grey-100", 0xcfd8dc),
+    ("blue-grey-200", 0xb0bec5),
+    ("blue-grey-300", 0x90a4ae),
+    ("blue-grey-400", 0x78909c),
+    ("blue-grey-500", 0x607d8b),
+    ("blue-grey-600", 0x546e7a),
+    ("blue-grey-700", 0x455a64),
+    ("blue-grey-800", 0x37474f),
+    ("blue-grey-900", 0x263238),
+];
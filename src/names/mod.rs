@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use color::Color;
+
+mod css;
+mod material;
+mod okabe_ito;
+mod tailwind;
+mod tol;
+mod x11;
+mod xkcd;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Namespace {
+    Css,
+    X11,
+    Xkcd,
+    Tailwind,
+    Material,
+    OkabeIto,
+    Tol,
+}
+impl Namespace {
+    /// Namespaces in the order unprefixed names are looked up in.
+    pub const ALL: [Namespace; 3] = [Namespace::Css, Namespace::X11, Namespace::Xkcd];
+    /// Palette presets, which are only found with their prefix, since their
+    /// names ("blue-500", "muted-rose") only make sense within the set.
+    pub const PRESETS: [Namespace; 4] = [
+        Namespace::Tailwind,
+        Namespace::Material,
+        Namespace::OkabeIto,
+        Namespace::Tol,
+    ];
+
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Namespace::Css => "css",
+            Namespace::X11 => "x11",
+            Namespace::Xkcd => "xkcd",
+            Namespace::Tailwind => "tailwind",
+            Namespace::Material => "material",
+            Namespace::OkabeIto => "okabe-ito",
+            Namespace::Tol => "tol",
+        }
+    }
+    pub fn from_prefix(prefix: &str) -> Option<Namespace> {
+        let prefix = loose_key(prefix);
+        Namespace::ALL
+            .iter()
+            .chain(Namespace::PRESETS.iter())
+            .cloned()
+            .find(|ns| loose_key(ns.prefix()) == prefix)
+    }
+    fn names(self) -> &'static [(&'static str, u32)] {
+        match self {
+            Namespace::Css => css::NAMES,
+            Namespace::X11 => x11::NAMES,
+            Namespace::Xkcd => xkcd::NAMES,
+            Namespace::Tailwind => tailwind::NAMES,
+            Namespace::Material => material::NAMES,
+            Namespace::OkabeIto => okabe_ito::NAMES,
+            Namespace::Tol => tol::NAMES,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Name {
+    pub namespace: Namespace,
+    pub name: &'static str,
+    pub color: Color,
+}
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.namespace {
+            Namespace::Css => write!(f, "{}", self.name),
+            ns => write!(f, "{}:{}", ns.prefix(), self.name),
+        }
+    }
+}
+
+fn rgb(rgb: u32) -> Color {
+    Color((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+// Lowercased with runs of whitespace collapsed, so "Dusty  Rose" finds "dusty rose".
+fn exact_key(s: &str) -> String {
+    s.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+// Lowercased with all separators removed, so "DarkSlateGray" finds "dark slate gray".
+fn loose_key(s: &str) -> String {
+    s.chars()
+        .filter(|&c| !c.is_whitespace() && c != '-' && c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+struct Index {
+    exact: HashMap<String, usize>,
+    loose: HashMap<String, usize>,
+}
+impl Index {
+    fn new(names: &[(&str, u32)]) -> Index {
+        let mut exact = HashMap::with_capacity(names.len());
+        let mut loose = HashMap::with_capacity(names.len());
+        for (i, &(name, _)) in names.iter().enumerate() {
+            exact.entry(exact_key(name)).or_insert(i);
+            // Some namespaces (xkcd) have both "blue green" and "bluegreen";
+            // the exact table disambiguates those, the first one wins here.
+            loose.entry(loose_key(name)).or_insert(i);
+        }
+        Index { exact, loose }
+    }
+    fn find(&self, s: &str) -> Option<usize> {
+        self.exact
+            .get(&exact_key(s))
+            .or_else(|| self.loose.get(&loose_key(s)))
+            .cloned()
+    }
+}
+
+lazy_static! {
+    static ref INDICES: HashMap<Namespace, Index> = Namespace::ALL
+        .iter()
+        .chain(Namespace::PRESETS.iter())
+        .map(|&ns| (ns, Index::new(ns.names())))
+        .collect();
+}
+
+pub fn lookup_in(namespace: Namespace, s: &str) -> Option<Name> {
+    INDICES[&namespace].find(s).map(|i| {
+        let (name, color) = namespace.names()[i];
+        Name {
+            namespace,
+            name,
+            color: rgb(color),
+        }
+    })
+}
+
+/// Every name in a namespace, in its usual order.
+pub fn all_in(namespace: Namespace) -> Vec<Name> {
+    namespace
+        .names()
+        .iter()
+        .map(|&(name, color)| Name {
+            namespace,
+            name,
+            color: rgb(color),
+        })
+        .collect()
+}
+
+/// Looks up a color name, either as `namespace:name` or by trying each
+/// namespace in turn.
+pub fn lookup(s: &str) -> Option<Name> {
+    let s = s.trim();
+    if let Some(i) = s.find(':') {
+        if let Some(ns) = Namespace::from_prefix(&s[..i]) {
+            return lookup_in(ns, &s[i + 1..]);
+        }
+    }
+    Namespace::ALL
+        .iter()
+        .filter_map(|&ns| lookup_in(ns, s))
+        .next()
+}
+
+/// Finds the named color closest to `color`, with its CIEDE2000 distance.
+/// Ties go to the earlier namespace, so CSS names win over their copies.
+pub fn nearest(color: Color) -> (Name, f64) {
+    let mut best: Option<(Name, f64)> = None;
+    for &namespace in &Namespace::ALL {
+        for &(name, value) in namespace.names() {
+            let candidate = rgb(value);
+            let d = color.delta_e(candidate);
+            let better = match best {
+                Some((_, best_d)) => d < best_d,
+                None => true,
+            };
+            if better {
+                best = Some((
+                    Name {
+                        namespace,
+                        name,
+                        color: candidate,
+                    },
+                    d,
+                ));
+            }
+        }
+    }
+    best.expect("There are always names")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Option<String> {
+        lookup(s).map(|n| format!("{}", n.color))
+    }
+
+    #[test]
+    fn unprefixed_names_try_css_first() {
+        assert_eq!(hex("tomato"), Some(String::from("ff6347")));
+        assert_eq!(hex("gray"), Some(String::from("808080")));
+        assert_eq!(lookup("gray").map(|n| n.namespace), Some(Namespace::Css));
+        // Only in xkcd.
+        assert_eq!(hex("dusty rose"), Some(String::from("c0737a")));
+    }
+
+    #[test]
+    fn prefixes_pick_a_namespace() {
+        assert_eq!(hex("x11:gray"), Some(String::from("bebebe")));
+        assert_eq!(hex("xkcd:tomato"), Some(String::from("ef4026")));
+        assert_eq!(hex("XKCD:dusty rose"), Some(String::from("c0737a")));
+        assert_eq!(hex("x11:dusty rose"), None);
+    }
+
+    #[test]
+    fn case_and_spacing_are_ignored() {
+        assert_eq!(hex("TOMATO"), Some(String::from("ff6347")));
+        assert_eq!(hex("  Dusty   Rose "), Some(String::from("c0737a")));
+        assert_eq!(hex("Dark Slate Gray"), Some(String::from("2f4f4f")));
+        assert_eq!(hex("dark_slate-gray"), Some(String::from("2f4f4f")));
+    }
+
+    #[test]
+    fn unknown_names_are_none() {
+        assert!(lookup("not a color").is_none());
+        assert!(lookup("").is_none());
+    }
+
+    #[test]
+    fn names_display_with_their_prefix() {
+        assert_eq!(lookup("tomato").map(|n| n.to_string()), Some(String::from("tomato")));
+        assert_eq!(
+            lookup("xkcd:dusty rose").map(|n| n.to_string()),
+            Some(String::from("xkcd:dusty rose"))
+        );
+    }
+
+    #[test]
+    fn presets_resolve_with_their_prefix() {
+        assert_eq!(hex("tailwind:sky-500"), Some(String::from("0ea5e9")));
+        assert_eq!(hex("material:red-500"), Some(String::from("f44336")));
+        assert_eq!(hex("okabe-ito:vermillion"), Some(String::from("d55e00")));
+        assert_eq!(hex("tol:muted-indigo"), Some(String::from("332288")));
+        assert_eq!(hex("Tailwind:Sky 500"), Some(String::from("0ea5e9")));
+        // Unprefixed, the built-in names come first.
+        assert_eq!(hex("vermillion"), Some(String::from("f4320c")));
+        assert_eq!(hex("sky-500"), None);
+    }
+
+    #[test]
+    fn unknown_presets_and_names_are_errors() {
+        assert_eq!(hex("tailwind:sky-550"), None);
+        assert_eq!(hex("tol:vermillion"), None);
+        assert_eq!(hex("pantone:red"), None);
+        assert!("tailwind:sky-550".parse::<Color>().is_err());
+        assert!("pantone:red".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn every_preset_is_listed() {
+        for &ns in &Namespace::PRESETS {
+            assert_eq!(Namespace::from_prefix(ns.prefix()), Some(ns));
+            assert!(!all_in(ns).is_empty(), "{}", ns.prefix());
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// Okabe and Ito's palette of eight colors distinguishable with any common
+// color vision deficiency, from "Color Universal Design" (2008).
+
+pub static NAMES: &[(&str, u32)] = &[
+    ("black", 0x000000),
+    ("orange", 0xe69f00),
+    ("sky-blue", 0x56b4e9),
+    ("bluish-green", 0x009e73),
+    ("yellow", 0xf0e442),
+    ("blue", 0x0072b2),
+    ("vermillion", 0xd55e00),
+    ("reddish-purple", 0xcc79a7),
+];
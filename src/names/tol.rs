@@ -0,0 +1,29 @@
+// Paul Tol's bright, vibrant and muted qualitative schemes, which stay
+// distinct with color vision deficiencies.
+
+pub static NAMES: &[(&str, u32)] = &[
+    ("bright-blue", 0x4477aa),
+    ("bright-cyan", 0x66ccee),
+    ("bright-green", 0x228833),
+    ("bright-yellow", 0xccbb44),
+    ("bright-red", 0xee6677),
+    ("bright-purple", 0xaa3377),
+    ("bright-grey", 0xbbbbbb),
+    ("vibrant-blue", 0x0077bb),
+    ("vibrant-cyan", 0x33bbee),
+    ("vibrant-teal", 0x009988),
+    ("vibrant-orange", 0xee7733),
+    ("vibrant-red", 0xcc3311),
+    ("vibrant-magenta", 0xee3377),
+    ("vibrant-grey", 0xbbbbbb),
+    ("muted-indigo", 0x332288),
+    ("muted-cyan", 0x88ccee),
+    ("muted-teal", 0x44aa99),
+    ("muted-green", 0x117733),
+    ("muted-olive", 0x999933),
+    ("muted-sand", 0xddcc77),
+    ("muted-rose", 0xcc6677),
+    ("muted-wine", 0x882255),
+    ("muted-purple", 0xaa4499),
+    ("muted-pale-grey", 0xdddddd),
+];
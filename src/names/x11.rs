@@ -0,0 +1,662 @@
+// Color names from the X11 `rgb.txt`, deduplicated on their spaceless forms.
+
+pub static NAMES: &[(&str, u32)] = &[
+    ("snow", 0xfffafa),
+    ("ghost white", 0xf8f8ff),
+    ("white smoke", 0xf5f5f5),
+    ("gainsboro", 0xdcdcdc),
+    ("floral white", 0xfffaf0),
+    ("old lace", 0xfdf5e6),
+    ("linen", 0xfaf0e6),
+    ("antique white", 0xfaebd7),
+    ("papaya whip", 0xffefd5),
+    ("blanched almond", 0xffebcd),
+    ("bisque", 0xffe4c4),
+    ("peach puff", 0xffdab9),
+    ("navajo white", 0xffdead),
+    ("moccasin", 0xffe4b5),
+    ("cornsilk", 0xfff8dc),
+    ("ivory", 0xfffff0),
+    ("lemon chiffon", 0xfffacd),
+    ("seashell", 0xfff5ee),
+    ("honeydew", 0xf0fff0),
+    ("mint cream", 0xf5fffa),
+    ("azure", 0xf0ffff),
+    ("alice blue", 0xf0f8ff),
+    ("lavender", 0xe6e6fa),
+    ("lavender blush", 0xfff0f5),
+    ("misty rose", 0xffe4e1),
+    ("white", 0xffffff),
+    ("black", 0x000000),
+    ("dark slate gray", 0x2f4f4f),
+    ("dark slate grey", 0x2f4f4f),
+    ("dim gray", 0x696969),
+    ("dim grey", 0x696969),
+    ("slate gray", 0x708090),
+    ("slate grey", 0x708090),
+    ("light slate gray", 0x778899),
+    ("light slate grey", 0x778899),
+    ("gray", 0xbebebe),
+    ("grey", 0xbebebe),
+    ("light grey", 0xd3d3d3),
+    ("light gray", 0xd3d3d3),
+    ("midnight blue", 0x191970),
+    ("navy", 0x000080),
+    ("navy blue", 0x000080),
+    ("cornflower blue", 0x6495ed),
+    ("dark slate blue", 0x483d8b),
+    ("slate blue", 0x6a5acd),
+    ("medium slate blue", 0x7b68ee),
+    ("light slate blue", 0x8470ff),
+    ("medium blue", 0x0000cd),
+    ("royal blue", 0x4169e1),
+    ("blue", 0x0000ff),
+    ("dodger blue", 0x1e90ff),
+    ("deep sky blue", 0x00bfff),
+    ("sky blue", 0x87ceeb),
+    ("light sky blue", 0x87cefa),
+    ("steel blue", 0x4682b4),
+    ("light steel blue", 0xb0c4de),
+    ("light blue", 0xadd8e6),
+    ("powder blue", 0xb0e0e6),
+    ("pale turquoise", 0xafeeee),
+    ("dark turquoise", 0x00ced1),
+    ("medium turquoise", 0x48d1cc),
+    ("turquoise", 0x40e0d0),
+    ("cyan", 0x00ffff),
+    ("light cyan", 0xe0ffff),
+    ("cadet blue", 0x5f9ea0),
+    ("medium aquamarine", 0x66cdaa),
+    ("aquamarine", 0x7fffd4),
+    ("dark green", 0x006400),
+    ("dark olive green", 0x556b2f),
+    ("dark sea green", 0x8fbc8f),
+    ("sea green", 0x2e8b57),
+    ("medium sea green", 0x3cb371),
+    ("light sea green", 0x20b2aa),
+    ("pale green", 0x98fb98),
+    ("spring green", 0x00ff7f),
+    ("lawn green", 0x7cfc00),
+    ("green", 0x00ff00),
+    ("chartreuse", 0x7fff00),
+    ("medium spring green", 0x00fa9a),
+    ("green yellow", 0xadff2f),
+    ("lime green", 0x32cd32),
+    ("yellow green", 0x9acd32),
+    ("forest green", 0x228b22),
+    ("olive drab", 0x6b8e23),
+    ("dark khaki", 0xbdb76b),
+    ("khaki", 0xf0e68c),
+    ("pale goldenrod", 0xeee8aa),
+    ("light goldenrod yellow", 0xfafad2),
+    ("light yellow", 0xffffe0),
+    ("yellow", 0xffff00),
+    ("gold", 0xffd700),
+    ("light goldenrod", 0xeedd82),
+    ("goldenrod", 0xdaa520),
+    ("dark goldenrod", 0xb8860b),
+    ("rosy brown", 0xbc8f8f),
+    ("indian red", 0xcd5c5c),
+    ("saddle brown", 0x8b4513),
+    ("sienna", 0xa0522d),
+    ("peru", 0xcd853f),
+    ("burlywood", 0xdeb887),
+    ("beige", 0xf5f5dc),
+    ("wheat", 0xf5deb3),
+    ("sandy brown", 0xf4a460),
+    ("tan", 0xd2b48c),
+    ("chocolate", 0xd2691e),
+    ("firebrick", 0xb22222),
+    ("brown", 0xa52a2a),
+    ("dark salmon", 0xe9967a),
+    ("salmon", 0xfa8072),
+    ("light salmon", 0xffa07a),
+    ("orange", 0xffa500),
+    ("dark orange", 0xff8c00),
+    ("coral", 0xff7f50),
+    ("light coral", 0xf08080),
+    ("tomato", 0xff6347),
+    ("orange red", 0xff4500),
+    ("red", 0xff0000),
+    ("hot pink", 0xff69b4),
+    ("deep pink", 0xff1493),
+    ("pink", 0xffc0cb),
+    ("light pink", 0xffb6c1),
+    ("pale violet red", 0xdb7093),
+    ("maroon", 0xb03060),
+    ("medium violet red", 0xc71585),
+    ("violet red", 0xd02090),
+    ("magenta", 0xff00ff),
+    ("violet", 0xee82ee),
+    ("plum", 0xdda0dd),
+    ("orchid", 0xda70d6),
+    ("medium orchid", 0xba55d3),
+    ("dark orchid", 0x9932cc),
+    ("dark violet", 0x9400d3),
+    ("blue violet", 0x8a2be2),
+    ("purple", 0xa020f0),
+    ("medium purple", 0x9370db),
+    ("thistle", 0xd8bfd8),
+    ("snow1", 0xfffafa),
+    ("snow2", 0xeee9e9),
+    ("snow3", 0xcdc9c9),
+    ("snow4", 0x8b8989),
+    ("seashell1", 0xfff5ee),
+    ("seashell2", 0xeee5de),
+    ("seashell3", 0xcdc5bf),
+    ("seashell4", 0x8b8682),
+    ("antique white1", 0xffefdb),
+    ("antique white2", 0xeedfcc),
+    ("antique white3", 0xcdc0b0),
+    ("antique white4", 0x8b8378),
+    ("bisque1", 0xffe4c4),
+    ("bisque2", 0xeed5b7),
+    ("bisque3", 0xcdb79e),
+    ("bisque4", 0x8b7d6b),
+    ("peach puff1", 0xffdab9),
+    ("peach puff2", 0xeecbad),
+    ("peach puff3", 0xcdaf95),
+    ("peach puff4", 0x8b7765),
+    ("navajo white1", 0xffdead),
+    ("navajo white2", 0xeecfa1),
+    ("navajo white3", 0xcdb38b),
+    ("navajo white4", 0x8b795e),
+    ("lemon chiffon1", 0xfffacd),
+    ("lemon chiffon2", 0xeee9bf),
+    ("lemon chiffon3", 0xcdc9a5),
+    ("lemon chiffon4", 0x8b8970),
+    ("cornsilk1", 0xfff8dc),
+    ("cornsilk2", 0xeee8cd),
+    ("cornsilk3", 0xcdc8b1),
+    ("cornsilk4", 0x8b8878),
+    ("ivory1", 0xfffff0),
+    ("ivory2", 0xeeeee0),
+    ("ivory3", 0xcdcdc1),
+    ("ivory4", 0x8b8b83),
+    ("honeydew1", 0xf0fff0),
+    ("honeydew2", 0xe0eee0),
+    ("honeydew3", 0xc1cdc1),
+    ("honeydew4", 0x838b83),
+    ("lavender blush1", 0xfff0f5),
+    ("lavender blush2", 0xeee0e5),
+    ("lavender blush3", 0xcdc1c5),
+    ("lavender blush4", 0x8b8386),
+    ("misty rose1", 0xffe4e1),
+    ("misty rose2", 0xeed5d2),
+    ("misty rose3", 0xcdb7b5),
+    ("misty rose4", 0x8b7d7b),
+    ("azure1", 0xf0ffff),
+    ("azure2", 0xe0eeee),
+    ("azure3", 0xc1cdcd),
+    ("azure4", 0x838b8b),
+    ("slate blue1", 0x836fff),
+    ("slate blue2", 0x7a67ee),
+    ("slate blue3", 0x6959cd),
+    ("slate blue4", 0x473c8b),
+    ("royal blue1", 0x4876ff),
+    ("royal blue2", 0x436eee),
+    ("royal blue3", 0x3a5fcd),
+    ("royal blue4", 0x27408b),
+    ("blue1", 0x0000ff),
+    ("blue2", 0x0000ee),
+    ("blue3", 0x0000cd),
+    ("blue4", 0x00008b),
+    ("dodger blue1", 0x1e90ff),
+    ("dodger blue2", 0x1c86ee),
+    ("dodger blue3", 0x1874cd),
+    ("dodger blue4", 0x104e8b),
+    ("steel blue1", 0x63b8ff),
+    ("steel blue2", 0x5cacee),
+    ("steel blue3", 0x4f94cd),
+    ("steel blue4", 0x36648b),
+    ("deep sky blue1", 0x00bfff),
+    ("deep sky blue2", 0x00b2ee),
+    ("deep sky blue3", 0x009acd),
+    ("deep sky blue4", 0x00688b),
+    ("sky blue1", 0x87ceff),
+    ("sky blue2", 0x7ec0ee),
+    ("sky blue3", 0x6ca6cd),
+    ("sky blue4", 0x4a708b),
+    ("light sky blue1", 0xb0e2ff),
+    ("light sky blue2", 0xa4d3ee),
+    ("light sky blue3", 0x8db6cd),
+    ("light sky blue4", 0x607b8b),
+    ("slate gray1", 0xc6e2ff),
+    ("slate gray2", 0xb9d3ee),
+    ("slate gray3", 0x9fb6cd),
+    ("slate gray4", 0x6c7b8b),
+    ("light steel blue1", 0xcae1ff),
+    ("light steel blue2", 0xbcd2ee),
+    ("light steel blue3", 0xa2b5cd),
+    ("light steel blue4", 0x6e7b8b),
+    ("light blue1", 0xbfefff),
+    ("light blue2", 0xb2dfee),
+    ("light blue3", 0x9ac0cd),
+    ("light blue4", 0x68838b),
+    ("light cyan1", 0xe0ffff),
+    ("light cyan2", 0xd1eeee),
+    ("light cyan3", 0xb4cdcd),
+    ("light cyan4", 0x7a8b8b),
+    ("pale turquoise1", 0xbbffff),
+    ("pale turquoise2", 0xaeeeee),
+    ("pale turquoise3", 0x96cdcd),
+    ("pale turquoise4", 0x668b8b),
+    ("cadet blue1", 0x98f5ff),
+    ("cadet blue2", 0x8ee5ee),
+    ("cadet blue3", 0x7ac5cd),
+    ("cadet blue4", 0x53868b),
+    ("turquoise1", 0x00f5ff),
+    ("turquoise2", 0x00e5ee),
+    ("turquoise3", 0x00c5cd),
+    ("turquoise4", 0x00868b),
+    ("cyan1", 0x00ffff),
+    ("cyan2", 0x00eeee),
+    ("cyan3", 0x00cdcd),
+    ("cyan4", 0x008b8b),
+    ("dark slate gray1", 0x97ffff),
+    ("dark slate gray2", 0x8deeee),
+    ("dark slate gray3", 0x79cdcd),
+    ("dark slate gray4", 0x528b8b),
+    ("aquamarine1", 0x7fffd4),
+    ("aquamarine2", 0x76eec6),
+    ("aquamarine3", 0x66cdaa),
+    ("aquamarine4", 0x458b74),
+    ("dark sea green1", 0xc1ffc1),
+    ("dark sea green2", 0xb4eeb4),
+    ("dark sea green3", 0x9bcd9b),
+    ("dark sea green4", 0x698b69),
+    ("sea green1", 0x54ff9f),
+    ("sea green2", 0x4eee94),
+    ("sea green3", 0x43cd80),
+    ("sea green4", 0x2e8b57),
+    ("pale green1", 0x9aff9a),
+    ("pale green2", 0x90ee90),
+    ("pale green3", 0x7ccd7c),
+    ("pale green4", 0x548b54),
+    ("spring green1", 0x00ff7f),
+    ("spring green2", 0x00ee76),
+    ("spring green3", 0x00cd66),
+    ("spring green4", 0x008b45),
+    ("green1", 0x00ff00),
+    ("green2", 0x00ee00),
+    ("green3", 0x00cd00),
+    ("green4", 0x008b00),
+    ("chartreuse1", 0x7fff00),
+    ("chartreuse2", 0x76ee00),
+    ("chartreuse3", 0x66cd00),
+    ("chartreuse4", 0x458b00),
+    ("olive drab1", 0xc0ff3e),
+    ("olive drab2", 0xb3ee3a),
+    ("olive drab3", 0x9acd32),
+    ("olive drab4", 0x698b22),
+    ("dark olive green1", 0xcaff70),
+    ("dark olive green2", 0xbcee68),
+    ("dark olive green3", 0xa2cd5a),
+    ("dark olive green4", 0x6e8b3d),
+    ("khaki1", 0xfff68f),
+    ("khaki2", 0xeee685),
+    ("khaki3", 0xcdc673),
+    ("khaki4", 0x8b864e),
+    ("light goldenrod1", 0xffec8b),
+    ("light goldenrod2", 0xeedc82),
+    ("light goldenrod3", 0xcdbe70),
+    ("light goldenrod4", 0x8b814c),
+    ("light yellow1", 0xffffe0),
+    ("light yellow2", 0xeeeed1),
+    ("light yellow3", 0xcdcdb4),
+    ("light yellow4", 0x8b8b7a),
+    ("yellow1", 0xffff00),
+    ("yellow2", 0xeeee00),
+    ("yellow3", 0xcdcd00),
+    ("yellow4", 0x8b8b00),
+    ("gold1", 0xffd700),
+    ("gold2", 0xeec900),
+    ("gold3", 0xcdad00),
+    ("gold4", 0x8b7500),
+    ("goldenrod1", 0xffc125),
+    ("goldenrod2", 0xeeb422),
+    ("goldenrod3", 0xcd9b1d),
+    ("goldenrod4", 0x8b6914),
+    ("dark goldenrod1", 0xffb90f),
+    ("dark goldenrod2", 0xeead0e),
+    ("dark goldenrod3", 0xcd950c),
+    ("dark goldenrod4", 0x8b6508),
+    ("rosy brown1", 0xffc1c1),
+    ("rosy brown2", 0xeeb4b4),
+    ("rosy brown3", 0xcd9b9b),
+    ("rosy brown4", 0x8b6969),
+    ("indian red1", 0xff6a6a),
+    ("indian red2", 0xee6363),
+    ("indian red3", 0xcd5555),
+    ("indian red4", 0x8b3a3a),
+    ("sienna1", 0xff8247),
+    ("sienna2", 0xee7942),
+    ("sienna3", 0xcd6839),
+    ("sienna4", 0x8b4726),
+    ("burlywood1", 0xffd39b),
+    ("burlywood2", 0xeec591),
+    ("burlywood3", 0xcdaa7d),
+    ("burlywood4", 0x8b7355),
+    ("wheat1", 0xffe7ba),
+    ("wheat2", 0xeed8ae),
+    ("wheat3", 0xcdba96),
+    ("wheat4", 0x8b7e66),
+    ("tan1", 0xffa54f),
+    ("tan2", 0xee9a49),
+    ("tan3", 0xcd853f),
+    ("tan4", 0x8b5a2b),
+    ("chocolate1", 0xff7f24),
+    ("chocolate2", 0xee7621),
+    ("chocolate3", 0xcd661d),
+    ("chocolate4", 0x8b4513),
+    ("firebrick1", 0xff3030),
+    ("firebrick2", 0xee2c2c),
+    ("firebrick3", 0xcd2626),
+    ("firebrick4", 0x8b1a1a),
+    ("brown1", 0xff4040),
+    ("brown2", 0xee3b3b),
+    ("brown3", 0xcd3333),
+    ("brown4", 0x8b2323),
+    ("salmon1", 0xff8c69),
+    ("salmon2", 0xee8262),
+    ("salmon3", 0xcd7054),
+    ("salmon4", 0x8b4c39),
+    ("light salmon1", 0xffa07a),
+    ("light salmon2", 0xee9572),
+    ("light salmon3", 0xcd8162),
+    ("light salmon4", 0x8b5742),
+    ("orange1", 0xffa500),
+    ("orange2", 0xee9a00),
+    ("orange3", 0xcd8500),
+    ("orange4", 0x8b5a00),
+    ("dark orange1", 0xff7f00),
+    ("dark orange2", 0xee7600),
+    ("dark orange3", 0xcd6600),
+    ("dark orange4", 0x8b4500),
+    ("coral1", 0xff7256),
+    ("coral2", 0xee6a50),
+    ("coral3", 0xcd5b45),
+    ("coral4", 0x8b3e2f),
+    ("tomato1", 0xff6347),
+    ("tomato2", 0xee5c42),
+    ("tomato3", 0xcd4f39),
+    ("tomato4", 0x8b3626),
+    ("orange red1", 0xff4500),
+    ("orange red2", 0xee4000),
+    ("orange red3", 0xcd3700),
+    ("orange red4", 0x8b2500),
+    ("red1", 0xff0000),
+    ("red2", 0xee0000),
+    ("red3", 0xcd0000),
+    ("red4", 0x8b0000),
+    ("debian red", 0xd70751),
+    ("deep pink1", 0xff1493),
+    ("deep pink2", 0xee1289),
+    ("deep pink3", 0xcd1076),
+    ("deep pink4", 0x8b0a50),
+    ("hot pink1", 0xff6eb4),
+    ("hot pink2", 0xee6aa7),
+    ("hot pink3", 0xcd6090),
+    ("hot pink4", 0x8b3a62),
+    ("pink1", 0xffb5c5),
+    ("pink2", 0xeea9b8),
+    ("pink3", 0xcd919e),
+    ("pink4", 0x8b636c),
+    ("light pink1", 0xffaeb9),
+    ("light pink2", 0xeea2ad),
+    ("light pink3", 0xcd8c95),
+    ("light pink4", 0x8b5f65),
+    ("pale violet red1", 0xff82ab),
+    ("pale violet red2", 0xee799f),
+    ("pale violet red3", 0xcd6889),
+    ("pale violet red4", 0x8b475d),
+    ("maroon1", 0xff34b3),
+    ("maroon2", 0xee30a7),
+    ("maroon3", 0xcd2990),
+    ("maroon4", 0x8b1c62),
+    ("violet red1", 0xff3e96),
+    ("violet red2", 0xee3a8c),
+    ("violet red3", 0xcd3278),
+    ("violet red4", 0x8b2252),
+    ("magenta1", 0xff00ff),
+    ("magenta2", 0xee00ee),
+    ("magenta3", 0xcd00cd),
+    ("magenta4", 0x8b008b),
+    ("orchid1", 0xff83fa),
+    ("orchid2", 0xee7ae9),
+    ("orchid3", 0xcd69c9),
+    ("orchid4", 0x8b4789),
+    ("plum1", 0xffbbff),
+    ("plum2", 0xeeaeee),
+    ("plum3", 0xcd96cd),
+    ("plum4", 0x8b668b),
+    ("medium orchid1", 0xe066ff),
+    ("medium orchid2", 0xd15fee),
+    ("medium orchid3", 0xb452cd),
+    ("medium orchid4", 0x7a378b),
+    ("dark orchid1", 0xbf3eff),
+    ("dark orchid2", 0xb23aee),
+    ("dark orchid3", 0x9a32cd),
+    ("dark orchid4", 0x68228b),
+    ("purple1", 0x9b30ff),
+    ("purple2", 0x912cee),
+    ("purple3", 0x7d26cd),
+    ("purple4", 0x551a8b),
+    ("medium purple1", 0xab82ff),
+    ("medium purple2", 0x9f79ee),
+    ("medium purple3", 0x8968cd),
+    ("medium purple4", 0x5d478b),
+    ("thistle1", 0xffe1ff),
+    ("thistle2", 0xeed2ee),
+    ("thistle3", 0xcdb5cd),
+    ("thistle4", 0x8b7b8b),
+    ("gray0", 0x000000),
+    ("grey0", 0x000000),
+    ("gray1", 0x030303),
+    ("grey1", 0x030303),
+    ("gray2", 0x050505),
+    ("grey2", 0x050505),
+    ("gray3", 0x080808),
+    ("grey3", 0x080808),
+    ("gray4", 0x0a0a0a),
+    ("grey4", 0x0a0a0a),
+    ("gray5", 0x0d0d0d),
+    ("grey5", 0x0d0d0d),
+    ("gray6", 0x0f0f0f),
+    ("grey6", 0x0f0f0f),
+    ("gray7", 0x121212),
+    ("grey7", 0x121212),
+    ("gray8", 0x141414),
+    ("grey8", 0x141414),
+    ("gray9", 0x171717),
+    ("grey9", 0x171717),
+    ("gray10", 0x1a1a1a),
+    ("grey10", 0x1a1a1a),
+    ("gray11", 0x1c1c1c),
+    ("grey11", 0x1c1c1c),
+    ("gray12", 0x1f1f1f),
+    ("grey12", 0x1f1f1f),
+    ("gray13", 0x212121),
+    ("grey13", 0x212121),
+    ("gray14", 0x242424),
+    ("grey14", 0x242424),
+    ("gray15", 0x262626),
+    ("grey15", 0x262626),
+    ("gray16", 0x292929),
+    ("grey16", 0x292929),
+    ("gray17", 0x2b2b2b),
+    ("grey17", 0x2b2b2b),
+    ("gray18", 0x2e2e2e),
+    ("grey18", 0x2e2e2e),
+    ("gray19", 0x303030),
+    ("grey19", 0x303030),
+    ("gray20", 0x333333),
+    ("grey20", 0x333333),
+    ("gray21", 0x363636),
+    ("grey21", 0x363636),
+    ("gray22", 0x383838),
+    ("grey22", 0x383838),
+    ("gray23", 0x3b3b3b),
+    ("grey23", 0x3b3b3b),
+    ("gray24", 0x3d3d3d),
+    ("grey24", 0x3d3d3d),
+    ("gray25", 0x404040),
+    ("grey25", 0x404040),
+    ("gray26", 0x424242),
+    ("grey26", 0x424242),
+    ("gray27", 0x454545),
+    ("grey27", 0x454545),
+    ("gray28", 0x474747),
+    ("grey28", 0x474747),
+    ("gray29", 0x4a4a4a),
+    ("grey29", 0x4a4a4a),
+    ("gray30", 0x4d4d4d),
+    ("grey30", 0x4d4d4d),
+    ("gray31", 0x4f4f4f),
+    ("grey31", 0x4f4f4f),
+    ("gray32", 0x525252),
+    ("grey32", 0x525252),
+    ("gray33", 0x545454),
+    ("grey33", 0x545454),
+    ("gray34", 0x575757),
+    ("grey34", 0x575757),
+    ("gray35", 0x595959),
+    ("grey35", 0x595959),
+    ("gray36", 0x5c5c5c),
+    ("grey36", 0x5c5c5c),
+    ("gray37", 0x5e5e5e),
+    ("grey37", 0x5e5e5e),
+    ("gray38", 0x616161),
+    ("grey38", 0x616161),
+    ("gray39", 0x636363),
+    ("grey39", 0x636363),
+    ("gray40", 0x666666),
+    ("grey40", 0x666666),
+    ("gray41", 0x696969),
+    ("grey41", 0x696969),
+    ("gray42", 0x6b6b6b),
+    ("grey42", 0x6b6b6b),
+    ("gray43", 0x6e6e6e),
+    ("grey43", 0x6e6e6e),
+    ("gray44", 0x707070),
+    ("grey44", 0x707070),
+    ("gray45", 0x737373),
+    ("grey45", 0x737373),
+    ("gray46", 0x757575),
+    ("grey46", 0x757575),
+    ("gray47", 0x787878),
+    ("grey47", 0x787878),
+    ("gray48", 0x7a7a7a),
+    ("grey48", 0x7a7a7a),
+    ("gray49", 0x7d7d7d),
+    ("grey49", 0x7d7d7d),
+    ("gray50", 0x7f7f7f),
+    ("grey50", 0x7f7f7f),
+    ("gray51", 0x828282),
+    ("grey51", 0x828282),
+    ("gray52", 0x858585),
+    ("grey52", 0x858585),
+    ("gray53", 0x878787),
+    ("grey53", 0x878787),
+    ("gray54", 0x8a8a8a),
+    ("grey54", 0x8a8a8a),
+    ("gray55", 0x8c8c8c),
+    ("grey55", 0x8c8c8c),
+    ("gray56", 0x8f8f8f),
+    ("grey56", 0x8f8f8f),
+    ("gray57", 0x919191),
+    ("grey57", 0x919191),
+    ("gray58", 0x949494),
+    ("grey58", 0x949494),
+    ("gray59", 0x969696),
+    ("grey59", 0x969696),
+    ("gray60", 0x999999),
+    ("grey60", 0x999999),
+    ("gray61", 0x9c9c9c),
+    ("grey61", 0x9c9c9c),
+    ("gray62", 0x9e9e9e),
+    ("grey62", 0x9e9e9e),
+    ("gray63", 0xa1a1a1),
+    ("grey63", 0xa1a1a1),
+    ("gray64", 0xa3a3a3),
+    ("grey64", 0xa3a3a3),
+    ("gray65", 0xa6a6a6),
+    ("grey65", 0xa6a6a6),
+    ("gray66", 0xa8a8a8),
+    ("grey66", 0xa8a8a8),
+    ("gray67", 0xababab),
+    ("grey67", 0xababab),
+    ("gray68", 0xadadad),
+    ("grey68", 0xadadad),
+    ("gray69", 0xb0b0b0),
+    ("grey69", 0xb0b0b0),
+    ("gray70", 0xb3b3b3),
+    ("grey70", 0xb3b3b3),
+    ("gray71", 0xb5b5b5),
+    ("grey71", 0xb5b5b5),
+    ("gray72", 0xb8b8b8),
+    ("grey72", 0xb8b8b8),
+    ("gray73", 0xbababa),
+    ("grey73", 0xbababa),
+    ("gray74", 0xbdbdbd),
+    ("grey74", 0xbdbdbd),
+    ("gray75", 0xbfbfbf),
+    ("grey75", 0xbfbfbf),
+    ("gray76", 0xc2c2c2),
+    ("grey76", 0xc2c2c2),
+    ("gray77", 0xc4c4c4),
+    ("grey77", 0xc4c4c4),
+    ("gray78", 0xc7c7c7),
+    ("grey78", 0xc7c7c7),
+    ("gray79", 0xc9c9c9),
+    ("grey79", 0xc9c9c9),
+    ("gray80", 0xcccccc),
+    ("grey80", 0xcccccc),
+    ("gray81", 0xcfcfcf),
+    ("grey81", 0xcfcfcf),
+    ("gray82", 0xd1d1d1),
+    ("grey82", 0xd1d1d1),
+    ("gray83", 0xd4d4d4),
+    ("grey83", 0xd4d4d4),
+    ("gray84", 0xd6d6d6),
+    ("grey84", 0xd6d6d6),
+    ("gray85", 0xd9d9d9),
+    ("grey85", 0xd9d9d9),
+    ("gray86", 0xdbdbdb),
+    ("grey86", 0xdbdbdb),
+    ("gray87", 0xdedede),
+    ("grey87", 0xdedede),
+    ("gray88", 0xe0e0e0),
+    ("grey88", 0xe0e0e0),
+    ("gray89", 0xe3e3e3),
+    ("grey89", 0xe3e3e3),
+    ("gray90", 0xe5e5e5),
+    ("grey90", 0xe5e5e5),
+    ("gray91", 0xe8e8e8),
+    ("grey91", 0xe8e8e8),
+    ("gray92", 0xebebeb),
+    ("grey92", 0xebebeb),
+    ("gray93", 0xededed),
+    ("grey93", 0xededed),
+    ("gray94", 0xf0f0f0),
+    ("grey94", 0xf0f0f0),
+    ("gray95", 0xf2f2f2),
+    ("grey95", 0xf2f2f2),
+    ("gray96", 0xf5f5f5),
+    ("grey96", 0xf5f5f5),
+    ("gray97", 0xf7f7f7),
+    ("grey97", 0xf7f7f7),
+    ("gray98", 0xfafafa),
+    ("grey98", 0xfafafa),
+    ("gray99", 0xfcfcfc),
+    ("grey99", 0xfcfcfc),
+    ("gray100", 0xffffff),
+    ("grey100", 0xffffff),
+    ("dark grey", 0xa9a9a9),
+    ("dark gray", 0xa9a9a9),
+    ("dark blue", 0x00008b),
+    ("dark cyan", 0x008b8b),
+    ("dark magenta", 0x8b008b),
+    ("dark red", 0x8b0000),
+    ("light green", 0x90ee90),
+];
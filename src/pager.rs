@@ -0,0 +1,110 @@
+// Embeds paged through with reaction buttons. Pagers live in the client's
+// data, so old messages stop turning pages when the bot restarts.
+
+use std::collections::HashMap;
+
+use failure::Error;
+use serenity::CACHE;
+use serenity::builder::CreateEmbed;
+use serenity::client::Context;
+use serenity::model::channel::{Reaction, ReactionType};
+use serenity::model::id::{ChannelId, MessageId};
+use typemap::Key;
+
+const PREVIOUS: char = '◀';
+const NEXT: char = '▶';
+/// How many pagers to remember before forgetting the oldest.
+const LIMIT: usize = 100;
+
+pub struct Pagers;
+impl Key for Pagers {
+    type Value = HashMap<MessageId, Pager>;
+}
+
+#[derive(Debug, Clone)]
+pub struct Pager {
+    title: String,
+    footer: String,
+    pages: Vec<String>,
+    page: usize,
+}
+impl Pager {
+    /// A pager over `pages`, which are embed descriptions. `footer` follows
+    /// the page number.
+    pub fn new(title: String, footer: String, pages: Vec<String>) -> Pager {
+        Pager {
+            title,
+            footer,
+            pages,
+            page: 0,
+        }
+    }
+
+    fn embed(&self, e: CreateEmbed) -> CreateEmbed {
+        let mut footer = format!("Page {}/{}", self.page + 1, self.pages.len().max(1));
+        if !self.footer.is_empty() {
+            footer.push_str(&format!(" · {}", self.footer));
+        }
+        e.title(&self.title)
+            .description(self.pages.get(self.page).map_or("", |p| p.as_ref()))
+            .footer(|f| f.text(footer))
+    }
+
+    /// Sends the first page, adding page buttons if there's more than one.
+    pub fn send(self, ctx: &Context, channel: ChannelId) -> Result<(), Error> {
+        let msg = channel
+            .send_message(|m| m.embed(|e| self.embed(e)))
+            .map_err(|e| format_err!("Couldn't send list: {}", e))?;
+        if self.pages.len() < 2 {
+            return Ok(());
+        }
+
+        {
+            let mut data = ctx.data.lock();
+            let pagers = data.entry::<Pagers>().or_insert_with(HashMap::new);
+            if pagers.len() >= LIMIT {
+                // Snowflakes grow over time, so the smallest ID is the oldest.
+                if let Some(&oldest) = pagers.keys().min() {
+                    pagers.remove(&oldest);
+                }
+            }
+            pagers.insert(msg.id, self);
+        }
+        let _ = msg.react(PREVIOUS);
+        let _ = msg.react(NEXT);
+        Ok(())
+    }
+}
+
+/// Turns a pager's page if `reaction` is one of its buttons.
+pub fn on_reaction(ctx: &Context, reaction: &Reaction) {
+    if reaction.user_id == CACHE.read().user.id {
+        return;
+    }
+    let step: isize = match reaction.emoji {
+        ReactionType::Unicode(ref s) if s.starts_with(PREVIOUS) => -1,
+        ReactionType::Unicode(ref s) if s.starts_with(NEXT) => 1,
+        _ => return,
+    };
+
+    let pager = {
+        let mut data = ctx.data.lock();
+        let pager = match data
+            .get_mut::<Pagers>()
+            .and_then(|p| p.get_mut(&reaction.message_id))
+        {
+            Some(pager) => pager,
+            None => return,
+        };
+        let len = pager.pages.len() as isize;
+        pager.page = ((pager.page as isize + step + len) % len) as usize;
+        pager.clone()
+    };
+
+    let _ = reaction
+        .channel_id
+        .edit_message(reaction.message_id, |m| m.embed(|e| pager.embed(e)));
+    // Take the reaction back off so the button can be pressed again. This
+    // needs Manage Messages; without it, people can remove it themselves.
+    let _ = reaction.delete();
+}
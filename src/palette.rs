@@ -0,0 +1,167 @@
+// The admin-curated palette, stored in `palette.cdb` as hex colors mapped to
+// the names they were added by (or nothing).
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::Path;
+use std::str;
+
+use failure::Error;
+
+use color::Color;
+use db;
+
+/// What `%color set` does with colors that aren't on the palette.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    Reject,
+    Snap,
+}
+impl Mode {
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::Off => "off",
+            Mode::Reject => "reject",
+            Mode::Snap => "snap",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Mode> {
+        match name.trim().to_lowercase().as_ref() {
+            "off" => Some(Mode::Off),
+            "reject" => Some(Mode::Reject),
+            "snap" => Some(Mode::Snap),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub color: Color,
+    pub name: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "#{}", self.color)
+        } else {
+            write!(f, "{} (#{})", self.name, self.color)
+        }
+    }
+}
+
+pub fn load(data: &Path) -> Result<Vec<Entry>, Error> {
+    let mut entries = Vec::new();
+    if let Some(mut db) = db::Guild::Palette.open(data)? {
+        for (k, v) in db.iter() {
+            let color = str::from_utf8(k)
+                .ok()
+                .and_then(|s| s.parse::<Color>().ok())
+                .ok_or_else(|| format_err!("Palette color `{:?}` is invalid.", k))?;
+            let name = str::from_utf8(v)
+                .map_err(|e| format_err!("Palette name for #{} wasn't UTF-8: {}", color, e))?;
+            entries.push(Entry {
+                color,
+                name: name.to_string(),
+            });
+        }
+    }
+    Ok(entries)
+}
+
+pub fn store(data: &Path, entries: &[Entry]) -> Result<(), Error> {
+    db::ensure_dir(data)?;
+    db::Guild::Palette.rm_tmp(data)?;
+    db::Guild::Palette.set(
+        data,
+        |ndb| {
+            for entry in entries {
+                let _ = ndb.add(format!("{}", entry.color).as_bytes(), entry.name.as_bytes());
+            }
+        },
+        |_| (),
+    )
+}
+
+/// The palette entry closest to `color`, with its CIEDE2000 distance.
+pub fn nearest(entries: &[Entry], color: Color) -> Option<(&Entry, f64)> {
+    entries
+        .iter()
+        .map(|e| (e, color.delta_e(e.color)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+}
+
+/// What `%color set` makes of a color under a palette mode.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fit<'a> {
+    /// There's no palette to keep to.
+    Free,
+    /// The color is this entry, or snaps to it.
+    Palette(&'a Entry),
+    /// The color isn't on the palette, and this is the closest entry, if the
+    /// palette has any.
+    Outside(Option<&'a Entry>),
+}
+
+/// How `color` fits the palette `entries` under `mode`, snapping to the
+/// nearest entry if `snap` allows it. An empty palette allows nothing.
+pub fn fit<'a>(mode: Mode, entries: &'a [Entry], color: Color, snap: bool) -> Fit<'a> {
+    if mode == Mode::Off {
+        return Fit::Free;
+    }
+    match nearest(entries, color) {
+        Some((entry, _)) if entry.color == color => Fit::Palette(entry),
+        Some((entry, _)) if mode == Mode::Snap && snap => Fit::Palette(entry),
+        closest => Fit::Outside(closest.map(|(entry, _)| entry)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            Entry {
+                color: Color(0xff, 0, 0),
+                name: String::from("red"),
+            },
+            Entry {
+                color: Color(0, 0, 0xff),
+                name: String::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn off_allows_anything() {
+        assert_eq!(fit(Mode::Off, &entries(), Color(1, 2, 3), false), Fit::Free);
+        assert_eq!(fit(Mode::Off, &[], Color(1, 2, 3), false), Fit::Free);
+    }
+
+    #[test]
+    fn palette_colors_are_allowed() {
+        let entries = entries();
+        for &mode in &[Mode::Reject, Mode::Snap] {
+            assert_eq!(fit(mode, &entries, Color(0, 0, 0xff), false), Fit::Palette(&entries[1]));
+        }
+    }
+
+    #[test]
+    fn others_are_rejected_or_snapped() {
+        let entries = entries();
+        let reddish = Color(0xee, 0x11, 0x11);
+        assert_eq!(fit(Mode::Reject, &entries, reddish, true), Fit::Outside(Some(&entries[0])));
+        assert_eq!(fit(Mode::Snap, &entries, reddish, false), Fit::Outside(Some(&entries[0])));
+        assert_eq!(fit(Mode::Snap, &entries, reddish, true), Fit::Palette(&entries[0]));
+    }
+
+    #[test]
+    fn an_empty_palette_allows_nothing() {
+        for &mode in &[Mode::Reject, Mode::Snap] {
+            assert_eq!(fit(mode, &[], Color(0xff, 0, 0), true), Fit::Outside(None));
+        }
+    }
+}
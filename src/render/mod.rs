@@ -0,0 +1,145 @@
+use color::Color;
+use contrast::ratio;
+
+mod font;
+mod palette;
+mod png;
+mod preview;
+
+pub use self::palette::{palette, strip};
+pub use self::preview::preview;
+
+/// An RGB image to draw swatches on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: Color) -> Canvas {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 3);
+        for _ in 0..width * height {
+            pixels.extend_from_slice(&[background.0, background.1, background.2]);
+        }
+        Canvas {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Fills a rectangle, clipped to the canvas.
+    pub fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, color: Color) {
+        for py in y..(y + height).min(self.height) {
+            for px in x..(x + width).min(self.width) {
+                let i = (py as usize * self.width as usize + px as usize) * 3;
+                self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+    }
+
+    /// Draws `text` with its top left corner at (`x`, `y`), each font pixel
+    /// `scale` pixels wide, and returns the drawn width.
+    pub fn text(&mut self, x: u32, y: u32, scale: u32, color: Color, text: &str) -> u32 {
+        let mut cx = x;
+        for c in text.chars() {
+            let glyph = font::glyph(c);
+            for (row, bits) in glyph.iter().enumerate() {
+                for col in 0..font::WIDTH {
+                    if bits & (1 << (font::WIDTH - 1 - col)) != 0 {
+                        self.fill(
+                            cx + col * scale,
+                            y + row as u32 * scale,
+                            scale,
+                            scale,
+                            color,
+                        );
+                    }
+                }
+            }
+            cx += text_advance(scale);
+        }
+        cx - x
+    }
+
+    pub fn png(&self) -> Vec<u8> {
+        png::encode(self.width, self.height, &self.pixels)
+    }
+}
+
+fn text_advance(scale: u32) -> u32 {
+    (font::WIDTH + 1) * scale
+}
+
+/// How wide `text` would be drawn at `scale`.
+pub fn text_width(text: &str, scale: u32) -> u32 {
+    text.chars().count() as u32 * text_advance(scale)
+}
+
+/// How tall text is at `scale`.
+pub fn text_height(scale: u32) -> u32 {
+    font::HEIGHT * scale
+}
+
+/// Shortens `text` with a trailing `...` to fit in `width` at `scale`.
+pub fn fit_text(text: &str, scale: u32, width: u32) -> String {
+    if text_width(text, scale) <= width {
+        return text.to_string();
+    }
+    let max = (width / text_advance(scale)).saturating_sub(3) as usize;
+    let mut out: String = text.chars().take(max).collect();
+    out.push_str("...");
+    out
+}
+
+/// Black or white, whichever reads better on `background`.
+pub fn label_color(background: Color) -> Color {
+    let (black, white) = (Color(0, 0, 0), Color(0xff, 0xff, 0xff));
+    if ratio(black, background) >= ratio(white, background) {
+        black
+    } else {
+        white
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canvas_png_is_deterministic() {
+        let draw = || {
+            let mut canvas = Canvas::new(40, 20, Color(0xff, 0, 0));
+            canvas.fill(10, 5, 100, 100, Color(0, 0, 0xff));
+            canvas.text(1, 1, 1, Color(0xff, 0xff, 0xff), "Hi");
+            canvas.png()
+        };
+        assert_eq!(draw(), draw());
+    }
+
+    #[test]
+    fn fill_is_clipped_to_the_canvas() {
+        let mut canvas = Canvas::new(3, 2, Color(0xff, 0, 0));
+        canvas.fill(1, 1, 100, 100, Color(0, 0, 0xff));
+        assert_eq!(
+            canvas.pixels,
+            vec![
+                0xff, 0, 0, 0xff, 0, 0, 0xff, 0, 0, //
+                0xff, 0, 0, 0, 0, 0xff, 0, 0, 0xff,
+            ]
+        );
+    }
+
+    #[test]
+    fn long_text_is_shortened() {
+        assert_eq!(fit_text("short", 1, 100), "short");
+        let fitted = fit_text("a rather long role name", 1, 60);
+        assert!(fitted.ends_with("..."));
+        assert!(text_width(&fitted, 1) <= 60);
+    }
+}
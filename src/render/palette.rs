@@ -0,0 +1,53 @@
+use color::Color;
+
+use super::{fit_text, label_color, text_height, Canvas};
+
+const COLUMNS: u32 = 6;
+const CELL_WIDTH: u32 = 120;
+const CELL_HEIGHT: u32 = 64;
+const PAD: u32 = 8;
+/// Fills cells after the last color, like Discord's dark theme.
+const BACKGROUND: Color = Color(0x36, 0x39, 0x3f);
+
+/// A grid of `swatches` in the given order, each labelled with its hex code
+/// and member count.
+pub fn palette(swatches: &[(Color, usize)]) -> Canvas {
+    let cells = swatches
+        .iter()
+        .map(|&(color, members)| {
+            let caption = match members {
+                1 => String::from("1 member"),
+                n => format!("{} members", n),
+            };
+            (color, caption)
+        })
+        .collect::<Vec<_>>();
+    grid(&cells, COLUMNS)
+}
+
+/// A single row of `swatches`, each labelled with its hex code and caption.
+pub fn strip(swatches: &[(Color, String)]) -> Canvas {
+    grid(swatches, swatches.len().max(1) as u32)
+}
+
+fn grid(cells: &[(Color, String)], columns: u32) -> Canvas {
+    let rows = (cells.len() as u32).div_ceil(columns);
+    let mut canvas = Canvas::new(columns * CELL_WIDTH, rows.max(1) * CELL_HEIGHT, BACKGROUND);
+
+    for (i, &(color, ref caption)) in cells.iter().enumerate() {
+        let x = (i as u32 % columns) * CELL_WIDTH;
+        let y = (i as u32 / columns) * CELL_HEIGHT;
+        canvas.fill(x, y, CELL_WIDTH, CELL_HEIGHT, color);
+
+        let ink = label_color(color);
+        canvas.text(x + PAD, y + PAD, 2, ink, &format!("#{}", color));
+        canvas.text(
+            x + PAD,
+            y + CELL_HEIGHT - PAD - text_height(1),
+            1,
+            ink,
+            &fit_text(caption, 1, CELL_WIDTH - 2 * PAD),
+        );
+    }
+    canvas
+}
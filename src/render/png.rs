@@ -0,0 +1,225 @@
+// A minimal PNG encoder for 8-bit RGB images.
+//
+// Rows use the Sub filter, which turns flat runs of color into runs of zero
+// bytes, and the deflate stream only ever emits literals or repeats of the
+// previous byte (like zlib's Z_RLE strategy) with the fixed Huffman codes.
+// That's tiny for swatches and keeps the output byte-for-byte deterministic.
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Encodes `pixels`, `width * height` RGB triples in row-major order.
+pub fn encode(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    assert_eq!(pixels.len(), width as usize * height as usize * 3);
+
+    let mut png = SIGNATURE.to_vec();
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    // 8-bit depth, truecolor, deflate, adaptive filtering, no interlacing.
+    ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+    chunk(&mut png, b"IHDR", &ihdr);
+
+    let stride = width as usize * 3;
+    let mut filtered = Vec::with_capacity((stride + 1) * height as usize);
+    for row in pixels.chunks(stride.max(1)) {
+        filtered.push(1);
+        for (i, &byte) in row.iter().enumerate() {
+            let left = if i >= 3 { row[i - 3] } else { 0 };
+            filtered.push(byte.wrapping_sub(left));
+        }
+    }
+    chunk(&mut png, b"IDAT", &zlib(&filtered));
+
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
+
+struct BitWriter {
+    out: Vec<u8>,
+    bits: u32,
+    count: u32,
+}
+impl BitWriter {
+    fn write(&mut self, value: u32, count: u32) {
+        self.bits |= value << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.out.push(self.bits as u8);
+            self.bits >>= 8;
+            self.count -= 8;
+        }
+    }
+    // Huffman codes are packed starting from their most significant bit.
+    fn write_code(&mut self, code: u32, len: u32) {
+        let reversed = (0..len).fold(0, |r, i| (r << 1) | ((code >> i) & 1));
+        self.write(reversed, len);
+    }
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.out.push(self.bits as u8);
+        }
+        self.out
+    }
+}
+
+// (code, base length, extra bits) for deflate length codes 257 through 285.
+const LENGTHS: [(u32, u32, u32); 29] = [
+    (257, 3, 0),
+    (258, 4, 0),
+    (259, 5, 0),
+    (260, 6, 0),
+    (261, 7, 0),
+    (262, 8, 0),
+    (263, 9, 0),
+    (264, 10, 0),
+    (265, 11, 1),
+    (266, 13, 1),
+    (267, 15, 1),
+    (268, 17, 1),
+    (269, 19, 2),
+    (270, 23, 2),
+    (271, 27, 2),
+    (272, 31, 2),
+    (273, 35, 3),
+    (274, 43, 3),
+    (275, 51, 3),
+    (276, 59, 3),
+    (277, 67, 4),
+    (278, 83, 4),
+    (279, 99, 4),
+    (280, 115, 4),
+    (281, 131, 5),
+    (282, 163, 5),
+    (283, 195, 5),
+    (284, 227, 5),
+    (285, 258, 0),
+];
+
+// Writes a literal/length symbol with the fixed Huffman code.
+fn write_symbol(w: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => w.write_code(0b0011_0000 + symbol, 8),
+        144..=255 => w.write_code(0b1_1001_0000 + symbol - 144, 9),
+        256..=279 => w.write_code(symbol - 256, 7),
+        _ => w.write_code(0b1100_0000 + symbol - 280, 8),
+    }
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut w = BitWriter {
+        out: Vec::new(),
+        bits: 0,
+        count: 0,
+    };
+    // A single final block with fixed Huffman codes.
+    w.write(1, 1);
+    w.write(1, 2);
+
+    let mut i = 0;
+    while i < data.len() {
+        let byte = data[i];
+        write_symbol(&mut w, u32::from(byte));
+        i += 1;
+
+        let run = data[i..]
+            .iter()
+            .take(258)
+            .take_while(|&&b| b == byte)
+            .count() as u32;
+        if run >= 3 {
+            let &(code, base, extra) = LENGTHS
+                .iter()
+                .rev()
+                .find(|&&(_, base, _)| base <= run)
+                .expect("Run lengths start at 3");
+            write_symbol(&mut w, code);
+            w.write(run - base, extra);
+            // Distance code 0, a distance of 1.
+            w.write_code(0, 5);
+            i += run as usize;
+        }
+    }
+    write_symbol(&mut w, 256);
+    w.finish()
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary, fastest compression.
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(data));
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    // A 3×2 image: a red top row, and a red pixel then two blue below. The
+    // bytes were checked with Python's zlib, which agrees on every CRC and
+    // inflates the image data to the filtered rows.
+    #[test]
+    fn golden_bytes() {
+        let pixels = [
+            0xff, 0, 0, 0xff, 0, 0, 0xff, 0, 0, //
+            0xff, 0, 0, 0, 0, 0xff, 0, 0, 0xff,
+        ];
+        let expected: [u8; 80] = [
+            0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48,
+            0x44, 0x52, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x08, 0x02, 0x00, 0x00,
+            0x00, 0x12, 0x16, 0xf1, 0x4d, 0x00, 0x00, 0x00, 0x17, 0x49, 0x44, 0x41, 0x54, 0x78,
+            0x01, 0x63, 0xfc, 0xcf, 0x00, 0x05, 0x8c, 0xff, 0x19, 0x18, 0x18, 0x19, 0xfe, 0x33,
+            0x30, 0x30, 0x00, 0x00, 0x20, 0x18, 0x03, 0x01, 0x0e, 0x21, 0x6b, 0x6d, 0x00, 0x00,
+            0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+        ];
+        assert_eq!(&encode(3, 2, &pixels)[..], &expected[..]);
+    }
+
+    #[test]
+    fn long_runs_are_compressed() {
+        // A flat 256×256 swatch filters to rows of zeros, which are runs.
+        let pixels = vec![0x40; 256 * 256 * 3];
+        assert!(encode(256, 256, &pixels).len() < pixels.len() / 50);
+    }
+}
@@ -0,0 +1,75 @@
+use color::Color;
+use contrast::{ratio, Theme};
+use cvd::Deficiency;
+
+use super::{fit_text, label_color, text_height, text_width, Canvas};
+
+const WIDTH: u32 = 400;
+const HEADER: u32 = 72;
+const ROW: u32 = 40;
+const CVD_ROW: u32 = 28;
+const PAD: u32 = 12;
+
+/// A swatch of `color` labelled with `label`, above `sample` text (usually a
+/// username) drawn in the color on each of Discord's themes, and then how
+/// the color looks with each color vision deficiency.
+pub fn preview(color: Color, label: &str, sample: &str) -> Canvas {
+    let rows = Theme::ALL.len() as u32;
+    let cvd_top = HEADER + rows * ROW;
+    let cvd_rows = Deficiency::ALL.len() as u32;
+    let mut canvas = Canvas::new(WIDTH, cvd_top + cvd_rows * CVD_ROW, color);
+
+    let ink = label_color(color);
+    canvas.text(PAD, PAD, 3, ink, &format!("#{}", color));
+    canvas.text(
+        PAD,
+        HEADER - PAD - text_height(2),
+        2,
+        ink,
+        &fit_text(label, 2, WIDTH - 2 * PAD),
+    );
+
+    for (i, &theme) in Theme::ALL.iter().enumerate() {
+        let y = HEADER + i as u32 * ROW;
+        theme_row(&mut canvas, y, theme, color, sample);
+    }
+    for (i, &deficiency) in Deficiency::ALL.iter().enumerate() {
+        let y = cvd_top + i as u32 * CVD_ROW;
+        cvd_row(&mut canvas, y, deficiency, color);
+    }
+    canvas
+}
+
+/// Draws `sample` in `color` on a theme's background, labelled with the
+/// theme and the contrast between them.
+fn theme_row(canvas: &mut Canvas, y: u32, theme: Theme, color: Color, sample: &str) {
+    let width = canvas.width();
+    canvas.fill(0, y, width, ROW, theme.background());
+
+    let note = format!("{} {:.1}:1", theme.name(), ratio(color, theme.background()));
+    let note_width = text_width(&note, 1);
+    canvas.text(
+        width - PAD - note_width,
+        y + (ROW - text_height(1)) / 2,
+        1,
+        theme.text(),
+        &note,
+    );
+
+    let sample = fit_text(sample, 2, width - 3 * PAD - note_width);
+    canvas.text(PAD, y + (ROW - text_height(2)) / 2, 2, color, &sample);
+}
+
+/// Draws a swatch of `color` as simulated for `deficiency`.
+fn cvd_row(canvas: &mut Canvas, y: u32, deficiency: Deficiency, color: Color) {
+    let width = canvas.width();
+    let simulated = deficiency.simulate(color);
+    canvas.fill(0, y, width, CVD_ROW, simulated);
+    canvas.text(
+        PAD,
+        y + (CVD_ROW - text_height(1)) / 2,
+        1,
+        label_color(simulated),
+        &format!("{} #{}", deficiency.name(), simulated),
+    );
+}
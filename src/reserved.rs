@@ -0,0 +1,106 @@
+// Colors kept for staff, stored in `reserved.cdb` as hex colors mapped to the
+// ΔE radius around them that members can't pick from.
+
+use std::path::Path;
+use std::str;
+
+use failure::Error;
+
+use color::Color;
+use db;
+
+/// The radius used when an admin doesn't give one.
+pub const DEFAULT_RADIUS: f64 = 10.0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Reservation {
+    pub color: Color,
+    pub radius: f64,
+}
+
+pub fn load(data: &Path) -> Result<Vec<Reservation>, Error> {
+    let mut reservations = Vec::new();
+    if let Some(mut db) = db::Guild::Reserved.open(data)? {
+        for (k, v) in db.iter() {
+            let color = str::from_utf8(k)
+                .ok()
+                .and_then(|s| s.parse::<Color>().ok())
+                .ok_or_else(|| format_err!("Reserved color `{:?}` is invalid.", k))?;
+            let radius = str::from_utf8(v)
+                .ok()
+                .and_then(|s| s.parse::<f64>().ok())
+                .ok_or_else(|| format_err!("Radius for reserved #{} is invalid.", color))?;
+            reservations.push(Reservation { color, radius });
+        }
+    }
+    Ok(reservations)
+}
+
+pub fn store(data: &Path, reservations: &[Reservation]) -> Result<(), Error> {
+    db::ensure_dir(data)?;
+    db::Guild::Reserved.rm_tmp(data)?;
+    db::Guild::Reserved.set(
+        data,
+        |ndb| {
+            for r in reservations {
+                let _ = ndb.add(
+                    format!("{}", r.color).as_bytes(),
+                    format!("{}", r.radius).as_bytes(),
+                );
+            }
+        },
+        |_| (),
+    )
+}
+
+/// The first reservation `color` falls inside, with its distance.
+pub fn conflict(reservations: &[Reservation], color: Color) -> Option<(Reservation, f64)> {
+    reservations
+        .iter()
+        .map(|&r| (r, color.delta_e(r.color)))
+        .find(|&(r, d)| d < r.radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAFF: Color = Color(0xe7, 0x4c, 0x3c);
+
+    fn reserve(radius: f64) -> Vec<Reservation> {
+        vec![Reservation {
+            color: STAFF,
+            radius,
+        }]
+    }
+
+    #[test]
+    fn colors_inside_the_radius_conflict() {
+        let near = Color(0xe0, 0x50, 0x40);
+        let d = near.delta_e(STAFF);
+        let (r, distance) = conflict(&reserve(d + 0.01), near).unwrap();
+        assert_eq!((r.color, distance), (STAFF, d));
+        assert!(conflict(&reserve(DEFAULT_RADIUS), STAFF).is_some());
+    }
+
+    #[test]
+    fn colors_outside_the_radius_dont() {
+        let near = Color(0xe0, 0x50, 0x40);
+        let d = near.delta_e(STAFF);
+        assert_eq!(conflict(&reserve(d - 0.01), near), None);
+        // The radius itself is just outside.
+        assert_eq!(conflict(&reserve(d), near), None);
+        assert_eq!(conflict(&reserve(DEFAULT_RADIUS), Color(0, 0x80, 0xff)), None);
+    }
+
+    #[test]
+    fn the_first_conflict_wins() {
+        let mut reservations = reserve(5.0);
+        reservations.push(Reservation {
+            color: Color(0xe8, 0x4c, 0x3c),
+            radius: 5.0,
+        });
+        let (r, _) = conflict(&reservations, STAFF).unwrap();
+        assert_eq!(r.color, STAFF);
+    }
+}
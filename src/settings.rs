@@ -0,0 +1,226 @@
+use std::path::Path;
+use std::str;
+
+use failure::Error;
+
+use contrast::{Policy, Theme};
+use db;
+use palette;
+
+/// Per-guild configuration, stored as strings in `settings.cdb`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// Requests within this CIEDE2000 distance of an existing color role
+    /// reuse it instead of creating a new one. 0 turns snapping off.
+    pub snap: f64,
+    /// What to do with colors below `min_contrast` on any of `themes`:
+    /// nothing, warn, reject them, or adjust their lightness until they pass.
+    pub contrast: Policy,
+    pub min_contrast: f64,
+    pub themes: Vec<Theme>,
+    /// Colors within this CIEDE2000 distance of a staff role's color under
+    /// a simulated color vision deficiency get a warning. 0 turns it off.
+    pub cvd_distance: f64,
+    /// Whether requests must come from the admin palette, and if not, whether
+    /// they're rejected or snapped to the closest palette color.
+    pub palette_only: palette::Mode,
+    /// Every member's color must be at least this CIEDE2000 distance from
+    /// everyone else's. 0 lets members share colors.
+    pub unique: f64,
+}
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            snap: 0.0,
+            contrast: Policy::Warn,
+            min_contrast: 2.5,
+            themes: vec![Theme::Dark, Theme::Light],
+            cvd_distance: 10.0,
+            palette_only: palette::Mode::Off,
+            unique: 0.0,
+        }
+    }
+}
+
+/// Every setting, with a short description for `%color config`.
+pub const KEYS: &[(&str, &str)] = &[
+    (
+        "snap",
+        "ΔE within which requests reuse an existing color role, or `off`",
+    ),
+    (
+        "contrast",
+        "`off`, `warn`, `reject` or `adjust` colors below `min_contrast`",
+    ),
+    (
+        "min_contrast",
+        "minimum WCAG contrast ratio against each theme, from 1 to 21",
+    ),
+    (
+        "themes",
+        "chat themes contrast is checked against: `dark`, `light`, `amoled`",
+    ),
+    (
+        "cvd_distance",
+        "ΔE within which colors that look like a staff role's, to anyone or to colorblind members, get a warning, or `off`",
+    ),
+    (
+        "palette_only",
+        "`off`, or `reject` or `snap` colors that aren't on `%color palette allowed`",
+    ),
+    (
+        "unique",
+        "ΔE every member's color must keep from everyone else's, or `off` to allow sharing",
+    ),
+];
+
+impl Settings {
+    pub fn load(data: &Path) -> Result<Settings, Error> {
+        let mut settings = Settings::default();
+        if let Some(mut db) = db::Guild::Settings.open(data)? {
+            for &(key, _) in KEYS {
+                if let Some(value) = db.find(key.as_bytes()) {
+                    let value = str::from_utf8(value)
+                        .map_err(|e| format_err!("Setting `{}` wasn't UTF-8: {}", key, e))?;
+                    settings
+                        .apply(key, value)
+                        .map_err(|e| format_err!("Stored setting `{}` is invalid: {}", key, e))?;
+                }
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Parses a value and sets it, without storing it.
+    pub fn apply(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let value = value.trim();
+        match key {
+            "snap" => self.snap = parse_delta_e(value)?,
+            "contrast" => {
+                self.contrast = Policy::from_name(value)
+                    .ok_or_else(|| format_err!("\"{}\" isn't `off`, `warn`, `reject` or `adjust`.", value))?
+            }
+            "min_contrast" => {
+                self.min_contrast = match value.trim_end_matches(":1").parse::<f64>() {
+                    Ok(r) if (1.0..=21.0).contains(&r) => r,
+                    _ => bail!("\"{}\" isn't a contrast ratio between 1 and 21.", value),
+                }
+            }
+            "themes" => {
+                let themes = value
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|t| !t.is_empty())
+                    .map(|t| Theme::from_name(t).ok_or_else(|| format_err!("Unknown theme `{}`.", t)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // With no themes, every contrast check would pass.
+                if themes.is_empty() {
+                    bail!(
+                        "Give at least one theme: {}.",
+                        Theme::ALL
+                            .iter()
+                            .map(|t| format!("`{}`", t.name()))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                }
+                self.themes = themes
+            }
+            "cvd_distance" => self.cvd_distance = parse_delta_e(value)?,
+            "palette_only" => {
+                self.palette_only = palette::Mode::from_name(value)
+                    .ok_or_else(|| format_err!("\"{}\" isn't `off`, `reject` or `snap`.", value))?
+            }
+            "unique" => self.unique = parse_delta_e(value)?,
+            _ => bail!("Unknown setting `{}`.", key),
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Result<String, Error> {
+        match key {
+            "snap" => Ok(format_delta_e(self.snap)),
+            "contrast" => Ok(self.contrast.name().to_string()),
+            "min_contrast" => Ok(format!("{}", self.min_contrast)),
+            "themes" => Ok(self
+                .themes
+                .iter()
+                .map(|t| t.name())
+                .collect::<Vec<_>>()
+                .join(",")),
+            "cvd_distance" => Ok(format_delta_e(self.cvd_distance)),
+            "palette_only" => Ok(self.palette_only.name().to_string()),
+            "unique" => Ok(format_delta_e(self.unique)),
+            _ => bail!("Unknown setting `{}`.", key),
+        }
+    }
+
+    /// Validates and stores a setting, or resets it to its default if `value`
+    /// is `None`.
+    pub fn store(data: &Path, key: &str, value: Option<&str>) -> Result<Settings, Error> {
+        let mut settings = Settings::load(data)?;
+        match value {
+            Some(value) => settings.apply(key, value)?,
+            None => {
+                let default = Settings::default().get(key)?;
+                settings.apply(key, &default)?
+            }
+        }
+
+        db::ensure_dir(data)?;
+        let mut old = db::Guild::Settings.open(data)?;
+        db::Guild::Settings.rm_tmp(data)?;
+        db::Guild::Settings.set(
+            data,
+            |ndb| {
+                for (k, v) in old.iter_mut().flat_map(|db| db.iter()) {
+                    if k != key.as_bytes() {
+                        let _ = ndb.add(k, v);
+                    }
+                }
+                if let Some(value) = value {
+                    let _ = ndb.add(key.as_bytes(), value.trim().as_bytes());
+                }
+            },
+            |_| (),
+        )?;
+        Ok(settings)
+    }
+}
+
+fn parse_delta_e(value: &str) -> Result<f64, Error> {
+    if value == "off" {
+        return Ok(0.0);
+    }
+    match value.parse::<f64>() {
+        Ok(d) if (0.0..=100.0).contains(&d) => Ok(d),
+        _ => bail!("\"{}\" isn't a ΔE between 0 and 100, or `off`.", value),
+    }
+}
+fn format_delta_e(d: f64) -> String {
+    if d == 0.0 {
+        String::from("off")
+    } else {
+        format!("{}", d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn themes_are_parsed_as_a_list() {
+        let mut settings = Settings::default();
+        settings.apply("themes", "amoled, light").unwrap();
+        assert_eq!(settings.themes, vec![Theme::Amoled, Theme::Light]);
+        assert!(settings.apply("themes", "dark sepia").is_err());
+    }
+
+    #[test]
+    fn themes_cant_be_empty() {
+        let mut settings = Settings::default();
+        assert!(settings.apply("themes", ",").is_err());
+        assert!(settings.apply("themes", "").is_err());
+        assert_eq!(settings.themes, vec![Theme::Dark, Theme::Light]);
+    }
+}
@@ -0,0 +1,257 @@
+// Finding colors to offer members instead of the one they asked for.
+
+use std::cmp::Ordering;
+
+use color::{Color, Oklch, Rgb};
+
+/// Variations of `color` in OKLCh: lighter and darker, more and less
+/// chromatic, and hue-shifted up to 60° either way.
+pub fn around(color: Color) -> Vec<Color> {
+    let lch = Oklch::from(color);
+    let mut out = Vec::new();
+    for dl in -6..=6 {
+        for &chroma in &[0.5, 0.75, 1.0, 1.25] {
+            for dh in -6..=6 {
+                let c = Color::from(Oklch {
+                    l: (lch.l + f64::from(dl) * 0.04).clamp(0.0, 1.0),
+                    c: lch.c * chroma,
+                    h: lch.h + f64::from(dh) * 10.0,
+                });
+                if c != color {
+                    out.push(c);
+                }
+            }
+        }
+    }
+    out.sort();
+    out.dedup();
+    out
+}
+
+/// Up to `n` of `candidates` that pass `accept`, nearest to `color` first,
+/// skipping any within `spacing` of one already chosen so they're actually
+/// different options.
+pub fn nearest<F>(color: Color, candidates: &[Color], n: usize, spacing: f64, accept: F) -> Vec<Color>
+where
+    F: Fn(Color) -> bool,
+{
+    let mut candidates = candidates
+        .iter()
+        .map(|&c| (c, color.delta_e(c)))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+
+    let mut chosen: Vec<Color> = Vec::with_capacity(n);
+    for (c, _) in candidates {
+        if chosen.len() == n {
+            break;
+        }
+        if accept(c) && chosen.iter().all(|&o| c.delta_e(o) >= spacing) {
+            chosen.push(c);
+        }
+    }
+    chosen
+}
+
+/// An even spread of in-gamut colors, optionally limited to hues within 30°
+/// of `hue`.
+pub fn spread(hue: Option<f64>) -> Vec<Color> {
+    let mut out = Vec::new();
+    for l in 0..11 {
+        for c in 0..5 {
+            for h in 0..24 {
+                let h = f64::from(h) * 15.0;
+                if let Some(hue) = hue {
+                    let off = (h - hue).abs() % 360.0;
+                    if off.min(360.0 - off) > 30.0 {
+                        continue;
+                    }
+                }
+                let lch = Oklch {
+                    l: 0.35 + f64::from(l) * 0.05,
+                    c: 0.06 + f64::from(c) * 0.04,
+                    h,
+                };
+                // Colors the gamut can't hold are just duplicates of less
+                // chromatic ones.
+                if Rgb::from(lch).in_gamut() {
+                    out.push(Color::from(lch));
+                }
+            }
+        }
+    }
+    out.sort();
+    out.dedup();
+    out
+}
+
+/// Picks up to `n` of `candidates`, one at a time, each as far as possible
+/// from `existing` and the ones already picked. Ties, such as when there's
+/// nothing to be far from yet, go to the more chromatic color.
+pub fn farthest(existing: &[Color], candidates: &[Color], n: usize) -> Vec<Color> {
+    let chroma = candidates
+        .iter()
+        .map(|&c| Oklch::from(c).c)
+        .collect::<Vec<_>>();
+    let mut distance = candidates
+        .iter()
+        .map(|&c| {
+            existing
+                .iter()
+                .map(|&e| c.delta_e(e))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect::<Vec<_>>();
+
+    let mut chosen = Vec::with_capacity(n);
+    while chosen.len() < n {
+        let best = (0..candidates.len())
+            .filter(|&i| distance[i] > 0.0)
+            .max_by(|&a, &b| {
+                distance[a]
+                    .partial_cmp(&distance[b])
+                    .unwrap_or(Ordering::Equal)
+                    .then(chroma[a].partial_cmp(&chroma[b]).unwrap_or(Ordering::Equal))
+            });
+        let best = match best {
+            Some(best) => candidates[best],
+            None => break,
+        };
+        chosen.push(best);
+        for (i, &c) in candidates.iter().enumerate() {
+            distance[i] = distance[i].min(c.delta_e(best));
+        }
+    }
+    chosen
+}
+
+/// A color for `text` that's always the same for the same text. The FNV-1a
+/// hash picks the hue freely but keeps lightness and chroma in a band that
+/// avoids the muddy browns and grays raw hashes tend to land on.
+pub fn from_text(text: &str) -> Color {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in text.as_bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    let unit = |bits: u64| (bits & 0xffff) as f64 / 65_535.0;
+    Color::from(Oklch {
+        l: 0.62 + unit(hash >> 16) * 0.14,
+        c: 0.11 + unit(hash >> 32) * 0.06,
+        h: unit(hash) * 360.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_keeps_unique_mode_clear_of_taken_colors() {
+        const UNIQUE: f64 = 10.0;
+        let requested = Color(0xdd, 0x2e, 0x44);
+        let taken = [requested, Color(0xe0, 0x40, 0x30)];
+        let free = |c: Color| taken.iter().all(|&t| c.delta_e(t) >= UNIQUE);
+
+        let suggestions = nearest(requested, &around(requested), 3, UNIQUE / 2.0, free);
+        assert_eq!(suggestions.len(), 3);
+        for (i, &c) in suggestions.iter().enumerate() {
+            assert!(free(c), "#{}", c);
+            for &o in &suggestions[..i] {
+                assert!(c.delta_e(o) >= UNIQUE / 2.0, "#{} and #{}", c, o);
+            }
+        }
+        let distances = suggestions
+            .iter()
+            .map(|&c| requested.delta_e(c))
+            .collect::<Vec<_>>();
+        assert!(distances.windows(2).all(|w| w[0] <= w[1]), "{:?}", distances);
+    }
+
+    #[test]
+    fn nearest_can_come_up_empty() {
+        let color = Color(0x80, 0x80, 0x80);
+        assert!(nearest(color, &around(color), 3, 5.0, |_| false).is_empty());
+    }
+
+    fn min_distance(c: Color, others: &[Color]) -> f64 {
+        others
+            .iter()
+            .map(|&o| c.delta_e(o))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn farthest_maximizes_the_minimum_distance() {
+        let members = [
+            Color(0xdd, 0x2e, 0x44),
+            Color(0x78, 0xb1, 0x59),
+            Color(0x55, 0xac, 0xee),
+        ];
+        let candidates = spread(None);
+        let best = farthest(&members, &candidates, 1)[0];
+        let most = candidates
+            .iter()
+            .map(|&c| min_distance(c, &members))
+            .fold(0.0, f64::max);
+        assert_eq!(min_distance(best, &members), most);
+    }
+
+    #[test]
+    fn farthest_spreads_out_its_picks() {
+        let members = [Color(0xdd, 0x2e, 0x44)];
+        let candidates = spread(None);
+        let picks = farthest(&members, &candidates, 4);
+        assert_eq!(picks.len(), 4);
+        // Each pick is the farthest from everything before it.
+        for i in 0..picks.len() {
+            let before = members.iter().chain(&picks[..i]).cloned().collect::<Vec<_>>();
+            let most = candidates
+                .iter()
+                .map(|&c| min_distance(c, &before))
+                .fold(0.0, f64::max);
+            assert_eq!(min_distance(picks[i], &before), most, "pick {}", i);
+        }
+    }
+
+    #[test]
+    fn farthest_skips_existing_colors() {
+        let candidates = [Color(1, 2, 3), Color(4, 5, 6)];
+        assert_eq!(farthest(&candidates, &candidates, 3), Vec::<Color>::new());
+        assert_eq!(farthest(&candidates[..1], &candidates, 3), vec![Color(4, 5, 6)]);
+    }
+
+    #[test]
+    fn spread_respects_the_hue() {
+        for c in spread(Some(200.0)) {
+            let h = Oklch::from(c).h;
+            assert!((h - 200.0).abs() <= 31.0, "#{} has hue {}", c, h);
+        }
+    }
+
+    #[test]
+    fn from_text_is_deterministic() {
+        assert_eq!(from_text("coloratura"), from_text("coloratura"));
+        assert_eq!(from_text(""), from_text(""));
+    }
+
+    #[test]
+    fn from_text_differs_for_different_text() {
+        let texts = ["alice", "bob", "Alice", "alice ", "carol", "dave"];
+        let colors = texts.iter().map(|t| from_text(t)).collect::<Vec<_>>();
+        for i in 0..colors.len() {
+            for j in 0..i {
+                assert_ne!(colors[i], colors[j], "{:?} and {:?}", texts[i], texts[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn from_text_stays_in_its_band() {
+        for i in 0..200 {
+            let lch = Oklch::from(from_text(&format!("member{}", i)));
+            assert!(lch.l > 0.55 && lch.l < 0.8, "lightness {}", lch.l);
+            assert!(lch.c > 0.03, "chroma {}", lch.c);
+        }
+    }
+}
@@ -2,9 +2,14 @@ use std::borrow::Cow;
 
 use failure::Error;
 use memchr::Memchr2;
+use serde_json::{Map, Value};
+use serenity::builder::CreateMessage;
 use serenity::client::Context;
 use serenity::framework::standard::{Args as SArgs, Command, CommandError};
+use serenity::http;
 use serenity::model::channel::Message;
+use serenity::model::id::ChannelId;
+use serenity::utils;
 
 pub struct CmdFn<F>(pub F);
 impl<F: Sync + Send + 'static + Fn(&mut Context, &Message, Args) -> Result<(), Error>> Command
@@ -18,6 +23,26 @@ impl<F: Sync + Send + 'static + Fn(&mut Context, &Message, Args) -> Result<(), E
     }
 }
 
+/// Sends a message with `image` attached. Serenity drops the embed from
+/// messages with files, so this sends the message as `payload_json`, letting
+/// its embed show the image with `attachment://<filename>`.
+pub fn send_image<F>(
+    channel: ChannelId,
+    image: &[u8],
+    filename: &str,
+    f: F,
+) -> Result<Message, Error>
+where
+    F: FnOnce(CreateMessage) -> CreateMessage,
+{
+    let CreateMessage(message, _) = f(CreateMessage::default());
+    let payload = Value::Object(utils::vecmap_to_json_map(message));
+    let mut form = Map::new();
+    form.insert(String::from("payload_json"), Value::String(payload.to_string()));
+    http::send_files(channel.0, vec![(image, filename)], form)
+        .map_err(|e| format_err!("Couldn't send image: {}", e))
+}
+
 #[derive(Debug, Clone)]
 pub struct Args<'a> {
     message: Option<&'a str>,
@@ -108,3 +133,77 @@ impl<'a> Iterator for Args<'a> {
         }
     }
 }
+impl<'a> Args<'a> {
+    /// Separates `--flag` arguments (returned without their dashes) from the
+    /// rest, keeping both in order. A lone `--` ends the flags, so everything
+    /// after it is kept as it is.
+    pub fn split_flags(self) -> (Vec<Cow<'a, str>>, Vec<Cow<'a, str>>) {
+        let mut flags = Vec::new();
+        let mut rest = Vec::new();
+        let mut args = self;
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                rest.extend(args);
+                break;
+            } else if let Some(flag) = arg.strip_prefix("--") {
+                flags.push(Cow::Owned(flag.to_string()));
+            } else {
+                rest.push(arg);
+            }
+        }
+        (flags, rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(message: &str) -> (Vec<String>, Vec<String>) {
+        let (flags, rest) = Args::new(message).split_flags();
+        (
+            flags.into_iter().map(Cow::into_owned).collect(),
+            rest.into_iter().map(Cow::into_owned).collect(),
+        )
+    }
+
+    fn strings(strs: &[&str]) -> Vec<String> {
+        strs.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn flags_before_and_after_positionals() {
+        assert_eq!(
+            split("--exact red --space=hsl blue"),
+            (strings(&["exact", "space=hsl"]), strings(&["red", "blue"]))
+        );
+        assert_eq!(split("red blue"), (strings(&[]), strings(&["red", "blue"])));
+        assert_eq!(split("--exact"), (strings(&["exact"]), strings(&[])));
+    }
+
+    #[test]
+    fn repeated_flags_are_all_kept() {
+        assert_eq!(
+            split("--space=hsl a --space=srgb b --space=hsl"),
+            (
+                strings(&["space=hsl", "space=srgb", "space=hsl"]),
+                strings(&["a", "b"])
+            )
+        );
+    }
+
+    #[test]
+    fn a_lone_double_dash_ends_the_flags() {
+        assert_eq!(
+            split("--exact -- --not-a-flag red"),
+            (strings(&["exact"]), strings(&["--not-a-flag", "red"]))
+        );
+        assert_eq!(split("--"), (strings(&[]), strings(&[])));
+        assert_eq!(split("red --"), (strings(&[]), strings(&["red"])));
+    }
+
+    #[test]
+    fn single_dashes_arent_flags() {
+        assert_eq!(split("-30"), (strings(&[]), strings(&["-30"])));
+    }
+}